@@ -32,7 +32,7 @@ use std::net::{SocketAddr, TcpStream};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use adsb_deku::{Frame, ICAO};
+use adsb_deku::{Error, Frame, ICAO};
 use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::event::{
@@ -417,13 +417,13 @@ fn main() -> Result<()> {
                         
                         // Apply same filtering logic
                         let df_adsb = if settings.opts.limit_parsing {
-                            !bytes.is_empty() && ((bytes[0] & 0b1111_1000) >> 3) == 17
+                            adsb_deku::peek(bytes).is_some_and(|header| header.df == 17)
                         } else {
                             true
                         };
                         
                         if df_adsb {
-                            let frame = Frame::from_bytes(bytes);
+                            let frame = Frame::from_bytes_validated(bytes);
                             match frame {
                                 Ok(frame) => {
                                     debug!("ADS-B Frame (BEAST): {frame}");
@@ -437,7 +437,15 @@ fn main() -> Result<()> {
                                     // update stats
                                     stats.update(&adsb_airplanes, airplane_added);
                                 }
-                                Err(e) => error!("BEAST frame decode error: {e:?}"),
+                                Err(e) => {
+                                    // bad parity is expected from noise, only count it
+                                    if let Error::BadParity { .. } = e {
+                                        debug!("BEAST frame decode error: {e}");
+                                    } else {
+                                        error!("BEAST frame decode error: {e}");
+                                    }
+                                    stats.track_error(&e);
+                                }
                             }
                         }
                     }
@@ -473,15 +481,15 @@ fn main() -> Result<()> {
 
                 // decode
                 // first check if the option is selected that limits the parsing by first checking the
-                // first 5 bits if they are the known adsb header DF field
+                // header for the known adsb DF field
                 let df_adsb = if settings.opts.limit_parsing {
-                    ((bytes[0] & 0b1111_1000) >> 3) == 17
+                    adsb_deku::peek(&bytes).is_some_and(|header| header.df == 17)
                 } else {
                     true
                 };
                 if df_adsb {
                     // parse the entire DF frame
-                    let frame = Frame::from_bytes(&bytes);
+                    let frame = Frame::from_bytes_validated(&bytes);
                     match frame {
                         Ok(frame) => {
                             debug!("ADS-B Frame: {frame}");
@@ -495,7 +503,15 @@ fn main() -> Result<()> {
                             // update stats
                             stats.update(&adsb_airplanes, airplane_added);
                         }
-                        Err(e) => error!("{e:?}"),
+                        Err(e) => {
+                            // bad parity is expected from noise, only count it
+                            if let Error::BadParity { .. } = e {
+                                debug!("{e}");
+                            } else {
+                                error!("{e}");
+                            }
+                            stats.track_error(&e);
+                        }
                     }
                 }
             }
@@ -609,11 +625,11 @@ fn init_tcp_reader(
                         settings.quit = Some(QuitReason::UserRequested);
                         return Ok(None);
                     }
-                    KeyCode::Char('c') => {
-                        if modifiers == crossterm::event::KeyModifiers::CONTROL {
-                            settings.quit = Some(QuitReason::UserRequested);
-                            return Ok(None);
-                        }
+                    KeyCode::Char('c')
+                        if modifiers == crossterm::event::KeyModifiers::CONTROL =>
+                    {
+                        settings.quit = Some(QuitReason::UserRequested);
+                        return Ok(None);
                     }
                     // unknown key
                     _ => (),
@@ -653,10 +669,8 @@ fn handle_keyevent(
         (KeyCode::F(5), _) => settings.tab_selection = Tab::Help,
         (KeyCode::Tab, _) => settings.tab_selection = settings.tab_selection.next_tab(),
         (KeyCode::Char('q'), _) => settings.quit = Some(QuitReason::UserRequested),
-        (KeyCode::Char('c'), _) => {
-            if modifiers == crossterm::event::KeyModifiers::CONTROL {
-                settings.quit = Some(QuitReason::UserRequested);
-            }
+        (KeyCode::Char('c'), _) if modifiers == crossterm::event::KeyModifiers::CONTROL => {
+            settings.quit = Some(QuitReason::UserRequested);
         }
         (KeyCode::Char('l'), _) => settings.opts.disable_lat_long ^= true,
         (KeyCode::Char('i'), _) => settings.opts.disable_icao ^= true,
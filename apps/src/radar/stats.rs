@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 use std::time::{Duration, SystemTime};
 
-use adsb_deku::ICAO;
+use adsb_deku::{Error, ICAO};
 use ratatui::layout::{Constraint, Rect};
 use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, Row, Table};
@@ -18,6 +18,16 @@ pub struct Stats {
     message_timestamps: VecDeque<SystemTime>,
     messages_per_second: f64,
     last_rate_update: SystemTime,
+    errors: DecodeErrors,
+}
+
+/// Messages that failed to decode, by cause
+#[derive(Debug, Default)]
+struct DecodeErrors {
+    bad_parity: u32,
+    unsupported_df: u32,
+    truncated: u32,
+    invalid_field: u32,
 }
 
 impl Default for Stats {
@@ -29,6 +39,7 @@ impl Default for Stats {
             message_timestamps: VecDeque::new(),
             messages_per_second: 0.0,
             last_rate_update: SystemTime::now(),
+            errors: DecodeErrors::default(),
         }
     }
 }
@@ -49,6 +60,16 @@ impl Stats {
         }
     }
 
+    pub fn track_error(&mut self, error: &Error) {
+        let count = match error {
+            Error::BadParity { .. } => &mut self.errors.bad_parity,
+            Error::UnsupportedDownlinkFormat(_) => &mut self.errors.unsupported_df,
+            Error::Truncated { .. } => &mut self.errors.truncated,
+            Error::InvalidField { .. } => &mut self.errors.invalid_field,
+        };
+        *count += 1;
+    }
+
     pub fn update_message_rate(&mut self) {
         let now = SystemTime::now();
 
@@ -147,6 +168,17 @@ pub fn build_tab_stats(
     let messages_per_sec_s = format!("{:.1}", stats.messages_per_second);
     rows.push(Row::new(vec!["Messages/Sec", "Live", &messages_per_sec_s]));
 
+    // Messages that failed to decode
+    let errors = &stats.errors;
+    for (name, count) in [
+        ("Bad Parity", errors.bad_parity),
+        ("Unsupported DF", errors.unsupported_df),
+        ("Truncated", errors.truncated),
+        ("Invalid Field", errors.invalid_field),
+    ] {
+        rows.push(Row::new(vec![name.to_string(), "All Time".to_string(), count.to_string()]));
+    }
+
     // draw table
     let widths = &[Constraint::Length(16), Constraint::Length(15), Constraint::Length(200)];
     let table = Table::new(rows, widths)
@@ -15,6 +15,7 @@ extern crate alloc;
 #[allow(non_snake_case)]
 fn _Unwind_Resume() {}
 
+// deku still needs an allocator for `DekuError`, and `Airplanes` is a `BTreeMap`
 use embedded_alloc::LlffHeap as Heap;
 
 #[global_allocator]
@@ -50,8 +50,18 @@ fn lax_message() {
     }
 }
 
+fn lax_message_fast() {
+    for line in TEST_STR.lines() {
+        let len = line.chars().count();
+        let hex = &mut line.to_string()[1..len - 1].to_string();
+        let bytes = hex::decode(&hex).unwrap();
+        let _frame = Frame::from_bytes_fast(&bytes).unwrap();
+    }
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("lax_messsages", |b| b.iter(lax_message));
+    c.bench_function("lax_messsages_fast", |b| b.iter(lax_message_fast));
     c.bench_function("get_position", |b| b.iter(b_get_position));
 }
 
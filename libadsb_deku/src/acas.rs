@@ -0,0 +1,284 @@
+//! ACAS coordination and resolution advisory data
+//!
+//! reference: ICAO Annex 10 Vol IV (4.3.8.4.2.4)
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    fmt,
+    fmt::Debug,
+    marker::Copy,
+    prelude::rust_2021::derive,
+    result::Result::Ok,
+    write, writeln,
+};
+
+use deku::prelude::*;
+
+use crate::{AC13Field, ICAO};
+
+/// MV: Message, ACAS of [`crate::DF::LongAirAir`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[deku(id_type = "u8")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MV {
+    /// VDS 3,0: Coordination reply with the current resolution advisories
+    #[deku(id = "0x30")]
+    ResolutionAdvisory(ResolutionAdvisory),
+
+    /// Other VDS values
+    #[deku(id_pat = "_")]
+    Unknown { vds: u8, data: [u8; 6] },
+}
+
+impl fmt::Display for MV {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ResolutionAdvisory(ra) => write!(f, "{ra}"),
+            Self::Unknown { .. } => Ok(()),
+        }
+    }
+}
+
+/// Resolution advisory data, as sent by ACAS in [`MV::ResolutionAdvisory`] and
+/// [`crate::adsb::AircraftStatus::ACASRaBroadcast`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolutionAdvisory {
+    /// ARA: Active Resolution Advisories, see [`Self::advisory`]
+    #[deku(bits = "14", endian = "big")]
+    pub ara: u16,
+    /// RAC: Resolution Advisory Complements
+    pub rac: ResolutionAdvisoryComplement,
+    /// RAT: RA Terminated
+    #[deku(bits = "1")]
+    pub rat: bool,
+    /// MTE: Multiple Threat Encounter
+    #[deku(bits = "1")]
+    pub mte: bool,
+    /// TTI and TID: Threat Type Indicator and Threat Identity Data
+    pub threat: ThreatIdentity,
+}
+
+impl ResolutionAdvisory {
+    /// Decode [`Self::ara`], which depends on its first bit and [`Self::mte`]
+    #[must_use]
+    pub fn advisory(&self) -> Advisory {
+        let bit = |n: u16| self.ara & (1 << (13 - n)) != 0;
+        if bit(0) {
+            Advisory::Single {
+                corrective: bit(1),
+                downward_sense: bit(2),
+                increased_rate: bit(3),
+                sense_reversal: bit(4),
+                altitude_crossing: bit(5),
+                positive: bit(6),
+            }
+        } else if self.mte {
+            Advisory::Multiple {
+                upward_correction: bit(1),
+                positive_climb: bit(2),
+                downward_correction: bit(3),
+                positive_descend: bit(4),
+                crossing: bit(5),
+                sense_reversal: bit(6),
+            }
+        } else {
+            Advisory::None
+        }
+    }
+}
+
+impl fmt::Display for ResolutionAdvisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  ACAS RA:       {}", self.advisory())?;
+        let rac = self.rac;
+        if rac.not_below || rac.not_above || rac.not_left || rac.not_right {
+            write!(f, "  RAC:          ")?;
+            if rac.not_below {
+                write!(f, " not-below")?;
+            }
+            if rac.not_above {
+                write!(f, " not-above")?;
+            }
+            if rac.not_left {
+                write!(f, " not-left")?;
+            }
+            if rac.not_right {
+                write!(f, " not-right")?;
+            }
+            writeln!(f)?;
+        }
+        if self.rat {
+            writeln!(f, "  RA terminated")?;
+        }
+        if self.mte {
+            writeln!(f, "  Multiple threat encounter")?;
+        }
+        write!(f, "{}", self.threat)
+    }
+}
+
+/// Active resolution advisories, from [`ResolutionAdvisory::advisory`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Advisory {
+    /// No vertical RA has been generated
+    None,
+    /// RA against one threat, or against every threat in the same sense
+    Single {
+        /// Corrective, otherwise preventive
+        corrective: bool,
+        /// Downward sense, otherwise upward
+        downward_sense: bool,
+        increased_rate: bool,
+        sense_reversal: bool,
+        altitude_crossing: bool,
+        /// Positive climb or descend, otherwise a vertical speed limit
+        positive: bool,
+    },
+    /// RAs against multiple threats in different senses
+    Multiple {
+        upward_correction: bool,
+        positive_climb: bool,
+        downward_correction: bool,
+        positive_descend: bool,
+        crossing: bool,
+        sense_reversal: bool,
+    },
+}
+
+impl fmt::Display for Advisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::None => write!(f, "none")?,
+            Self::Single {
+                corrective,
+                downward_sense,
+                increased_rate,
+                sense_reversal,
+                altitude_crossing,
+                positive,
+            } => {
+                write!(f, "{}", if corrective { "corrective" } else { "preventive" })?;
+                write!(
+                    f,
+                    " {}",
+                    match (downward_sense, positive) {
+                        (false, true) => "climb",
+                        (true, true) => "descend",
+                        (false, false) => "don't descend",
+                        (true, false) => "don't climb",
+                    }
+                )?;
+                if increased_rate {
+                    write!(f, ", increase rate")?;
+                }
+                if sense_reversal {
+                    write!(f, ", reversal")?;
+                }
+                if altitude_crossing {
+                    write!(f, ", crossing")?;
+                }
+            }
+            Self::Multiple {
+                upward_correction,
+                positive_climb,
+                downward_correction,
+                positive_descend,
+                crossing,
+                sense_reversal,
+            } => {
+                write!(f, "multiple threats")?;
+                if upward_correction {
+                    write!(f, ", upward correction")?;
+                }
+                if positive_climb {
+                    write!(f, ", climb")?;
+                }
+                if downward_correction {
+                    write!(f, ", downward correction")?;
+                }
+                if positive_descend {
+                    write!(f, ", descend")?;
+                }
+                if crossing {
+                    write!(f, ", crossing")?;
+                }
+                if sense_reversal {
+                    write!(f, ", reversal")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// RAC: Resolution Advisory Complements, received from other ACAS aircraft
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolutionAdvisoryComplement {
+    #[deku(bits = "1")]
+    pub not_below: bool,
+    #[deku(bits = "1")]
+    pub not_above: bool,
+    #[deku(bits = "1")]
+    pub not_left: bool,
+    #[deku(bits = "1")]
+    pub not_right: bool,
+}
+
+/// TTI: Threat Type Indicator, followed by TID: Threat Identity Data
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[deku(id_type = "u8", bits = "2")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThreatIdentity {
+    #[deku(id = "0")]
+    NoIdentity(#[deku(bits = "26", endian = "big")] u32),
+
+    /// Mode S address of the threat
+    #[deku(id = "1")]
+    Address(#[deku(pad_bits_after = "2")] ICAO),
+
+    /// Position of a threat without a Mode S address
+    #[deku(id = "2")]
+    Position {
+        /// TIDA: altitude
+        altitude: AC13Field,
+        /// TIDR: range, 0 no estimate, 1 less than 0.05 NM, 2..=126 (n - 1)/10 NM, 127 more
+        /// than 12.55 NM
+        #[deku(bits = "7")]
+        range: u8,
+        /// TIDB: bearing, 0 no estimate, 1..=60 between 6*(n - 1) and 6*n degrees
+        #[deku(bits = "6")]
+        bearing: u8,
+    },
+
+    #[deku(id = "3")]
+    Reserved(#[deku(bits = "26", endian = "big")] u32),
+}
+
+impl fmt::Display for ThreatIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoIdentity(_) | Self::Reserved(_) => (),
+            Self::Address(icao) => writeln!(f, "  Threat:        {icao}")?,
+            Self::Position { altitude, range, bearing } => {
+                writeln!(f, "  Threat alt:    {altitude}")?;
+                match range {
+                    0 => (),
+                    1 => writeln!(f, "  Threat range:  < 0.05 NM")?,
+                    127 => writeln!(f, "  Threat range:  > 12.55 NM")?,
+                    range => writeln!(f, "  Threat range:  {:.1} NM", f32::from(range - 1) / 10.0)?,
+                }
+                if (1..=60).contains(bearing) {
+                    writeln!(f, "  Threat brg:    {} deg", u16::from(*bearing - 1) * 6)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
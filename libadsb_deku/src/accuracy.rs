@@ -0,0 +1,145 @@
+//! Position accuracy and integrity from the type code of position messages
+//!
+//! reference: DO-260B 2.2.3.2.7.2.6, DO-260A 2.2.3.2.7.2.6 and DO-260 Table 2-14
+
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    default::Default,
+    fmt::Debug,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+};
+
+use crate::adsb::ADSBVersion;
+
+const NM: f64 = 1852.0;
+
+/// NIC supplement bits that, together with the type code, select the containment radius
+#[derive(Debug, PartialEq, Eq, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NicSupplements {
+    /// NIC-A, from [`crate::adsb::Integrity::nic_supplement_a`]
+    pub a: bool,
+    /// NIC-B, the [`crate::Altitude::saf_or_imf`] bit of airborne positions
+    pub b: bool,
+    /// NIC-C, from [`crate::adsb::Integrity::nic_supplement_c`]
+    pub c: bool,
+}
+
+/// Containment radius and position uncertainty in meters, from [`position_accuracy`]
+#[derive(Debug, PartialEq, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionAccuracy {
+    /// Rc: horizontal containment radius, HPL for version 0. `None` if unknown
+    pub rc: Option<f64>,
+    /// EPU: estimated position uncertainty (95%), `None` if unknown
+    pub epu: Option<f64>,
+}
+
+/// Accuracy of a position message with type code `tc`
+///
+/// Version 0 messages carry both values in the type code (NUCp). Later versions carry the
+/// integrity in the type code and its supplements, and the uncertainty in the NACp of the
+/// operational status, which is passed as `nacp`.
+///
+/// ```rust
+/// use adsb_deku::accuracy::{position_accuracy, NicSupplements};
+/// use adsb_deku::adsb::ADSBVersion;
+///
+/// let supplements = NicSupplements { a: true, b: true, c: false };
+/// let accuracy = position_accuracy(11, supplements, ADSBVersion::DOC9871AppendixC, Some(9));
+/// assert_eq!(accuracy.rc, Some(75.0));
+/// assert_eq!(accuracy.epu, Some(30.0));
+/// ```
+#[must_use]
+pub fn position_accuracy(
+    tc: u8,
+    supplements: NicSupplements,
+    version: ADSBVersion,
+    nacp: Option<u8>,
+) -> PositionAccuracy {
+    match version {
+        ADSBVersion::DOC9871AppendixA => {
+            let (rc, epu) = nuc_p(tc).unzip();
+            PositionAccuracy { rc, epu }
+        }
+        ADSBVersion::DOC9871AppendixB | ADSBVersion::DOC9871AppendixC => PositionAccuracy {
+            rc: containment_radius(tc, supplements, version),
+            epu: nacp.and_then(epu),
+        },
+    }
+}
+
+/// EPU in meters of a NACp, `None` if unknown
+#[must_use]
+pub fn epu(nacp: u8) -> Option<f64> {
+    Some(match nacp {
+        1 => 10.0 * NM,
+        2 => 4.0 * NM,
+        3 => 2.0 * NM,
+        4 => 1.0 * NM,
+        5 => 0.5 * NM,
+        6 => 0.3 * NM,
+        7 => 0.1 * NM,
+        8 => 0.05 * NM,
+        9 => 30.0,
+        10 => 10.0,
+        11 => 3.0,
+        _ => return None,
+    })
+}
+
+/// (HPL, μ) of the NUCp of version 0
+fn nuc_p(tc: u8) -> Option<(f64, f64)> {
+    Some(match tc {
+        5 | 9 | 20 => (7.5, 3.0),
+        6 | 10 | 21 => (25.0, 10.0),
+        7 | 11 => (0.1 * NM, 0.05 * NM),
+        8 | 12 => (0.2 * NM, 0.1 * NM),
+        13 => (0.5 * NM, 0.25 * NM),
+        14 => (1.0 * NM, 0.5 * NM),
+        15 => (2.0 * NM, 1.0 * NM),
+        16 => (10.0 * NM, 5.0 * NM),
+        17 => (20.0 * NM, 10.0 * NM),
+        _ => return None,
+    })
+}
+
+/// Rc in meters of versions 1 and 2, `None` if unknown or not a valid combination
+fn containment_radius(tc: u8, s: NicSupplements, version: ADSBVersion) -> Option<f64> {
+    let v2 = version == ADSBVersion::DOC9871AppendixC;
+    Some(match (tc, s.a, s.b, s.c) {
+        (5 | 9 | 20, ..) => 7.5,
+        (6 | 10 | 21, ..) => 25.0,
+
+        // surface
+        (7, true, _, false) => 75.0,
+        (7, false, _, false) => 0.1 * NM,
+        (8, true, _, true) if v2 => 0.2 * NM,
+        (8, false, _, true) if v2 => 0.3 * NM,
+        (8, true, _, false) if v2 => 0.6 * NM,
+
+        // airborne, NIC-B is only defined in version 2
+        (11, true, true, _) if v2 => 75.0,
+        (11, false, false, _) if v2 => 0.1 * NM,
+        (11, true, _, _) if !v2 => 75.0,
+        (11, false, _, _) if !v2 => 0.1 * NM,
+        (12, ..) => 0.2 * NM,
+        (13, false, true, _) if v2 => 0.3 * NM,
+        (13, false, false, _) if v2 => 0.5 * NM,
+        (13, true, true, _) if v2 => 0.6 * NM,
+        (13, false, _, _) if !v2 => 0.5 * NM,
+        (13, true, _, _) if !v2 => 0.6 * NM,
+        (14, ..) => 1.0 * NM,
+        (15, ..) => 2.0 * NM,
+        (16, true, true, _) if v2 => 4.0 * NM,
+        (16, false, false, _) if v2 => 8.0 * NM,
+        (16, true, _, _) if !v2 => 4.0 * NM,
+        (16, false, _, _) if !v2 => 8.0 * NM,
+        (17, ..) => 20.0 * NM,
+        _ => return None,
+    })
+}
@@ -26,11 +26,15 @@ use std::{fmt, i64};
 use deku::no_std_io::{Read, Seek};
 use deku::prelude::*;
 
-use crate::mode_ac::decode_id13_field;
-use crate::{aircraft_identification_read, Altitude, CPRFormat, Capability, Sign, ICAO};
+use crate::acas::ResolutionAdvisory;
+use crate::mode_ac::{decode_id13_field, encode_id13_field};
+use crate::{
+    aircraft_identification_read, aircraft_identification_write, Altitude, CPRFormat, Callsign,
+    Capability, Sign, ICAO,
+};
 
 /// [`crate::DF::ADSB`] || [`crate::DF::TisB`]
-#[derive(Debug, PartialEq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ADSB {
     /// Transponder Capability
@@ -55,7 +59,7 @@ impl ADSB {
 /// ADS-B Message, 5 first bits are known as Type Code (TC)
 ///
 /// reference: ICAO 9871 (A.2.3.1)
-#[derive(Debug, PartialEq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "5")]
 pub enum ME {
@@ -65,8 +69,9 @@ pub enum ME {
     #[deku(id = "19")]
     AirborneVelocity(AirborneVelocity),
 
+    /// The 51 bits after the type code
     #[deku(id = "0")]
-    NoPosition([u8; 6]),
+    NoPosition(#[deku(bits = "51", endian = "big")] u64),
 
     #[deku(id_pat = "1..=4")]
     AircraftIdentification {
@@ -82,22 +87,27 @@ pub enum ME {
     AirbornePositionGNSSAltitude { id: u8, altitude: Altitude },
 
     #[deku(id = "23")]
-    Reserved0([u8; 6]),
+    TestMessage(TestMessage),
 
     #[deku(id = "24")]
-    SurfaceSystemStatus([u8; 6]),
+    SurfaceSystemStatus(SurfaceSystemStatus),
 
     #[deku(id_pat = "25..=27")]
-    Reserved1 { id: u8, slice: [u8; 5] },
+    Reserved1 {
+        id: u8,
+        /// The 51 bits after the type code
+        #[deku(bits = "51", endian = "big")]
+        data: u64,
+    },
 
     #[deku(id = "28")]
     AircraftStatus(AircraftStatus),
 
     #[deku(id = "29")]
-    TargetStateAndStatusInformation(TargetStateAndStatusInformation),
+    TargetStateAndStatusInformation(TargetStateAndStatus),
 
     #[deku(id = "30")]
-    AircraftOperationalCoordination([u8; 6]),
+    AircraftOperationalCoordination(OperationalCoordination),
 
     #[deku(id = "31")]
     AircraftOperationStatus(OperationStatus),
@@ -123,19 +133,18 @@ impl ME {
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
             }
-            ME::AircraftIdentification {
-                id: _id,
-                identification: Identification { tc, ca, cn },
-            } => {
+            ME::AircraftIdentification { id: _id, identification } => {
+                let Identification { tc, ca, cn } = identification;
                 writeln!(f, " Extended Squitter{transponder}Aircraft identification and category")?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
                 writeln!(f, "  Ident:         {cn}")?;
-                writeln!(f, "  Category:      {tc}{ca}")?;
+                writeln!(f, "  Category:      {tc}{ca} ({})", identification.emitter_category())?;
             }
-            ME::SurfacePosition { .. } => {
+            ME::SurfacePosition { id: _, surface } => {
                 writeln!(f, " Extended Squitter{transponder}Surface position")?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
+                write!(f, "{surface}")?;
             }
             ME::AirbornePositionBaroAltitude { id: _tc, altitude } => {
                 writeln!(
@@ -150,7 +159,8 @@ impl ME {
                 AirborneVelocitySubType::GroundSpeedDecoding(_) => {
                     writeln!(
                         f,
-                        " Extended Squitter{transponder}Airborne velocity over ground, subsonic"
+                        " Extended Squitter{transponder}Airborne velocity over ground, {}",
+                        sonic(airborne_velocity)
                     )?;
                     writeln!(f, "  Address:       {icao} {address_type}")?;
                     writeln!(f, "  Air/Ground:    {capability}")?;
@@ -177,11 +187,27 @@ impl ME {
                         writeln!(f, "  Invalid packet")?;
                     }
                 }
-                AirborneVelocitySubType::AirspeedDecoding(airspeed_decoding) => {
-                    writeln!(f, " Extended Squitter{transponder}Airspeed and heading, subsonic",)?;
+                AirborneVelocitySubType::AirspeedDecoding(_) => {
+                    writeln!(
+                        f,
+                        " Extended Squitter{transponder}Airspeed and heading, {}",
+                        sonic(airborne_velocity)
+                    )?;
                     writeln!(f, "  Address:       {icao} {address_type}")?;
                     writeln!(f, "  Air/Ground:    {capability}")?;
-                    writeln!(f, "  IAS:           {} kt", airspeed_decoding.airspeed)?;
+                    if let Some(Velocity {
+                        horizontal:
+                            Some(HorizontalVelocity::Airspeed { speed, airspeed_type, heading }),
+                        ..
+                    }) = airborne_velocity.velocity()
+                    {
+                        if let Some(heading) = heading {
+                            writeln!(f, "  Heading:       {heading:.1} deg magnetic")?;
+                        }
+                        if let Some(speed) = speed {
+                            writeln!(f, "  {airspeed_type}:           {speed} kt")?;
+                        }
+                    }
                     if airborne_velocity.vrate_value > 0 {
                         writeln!(
                             f,
@@ -205,24 +231,81 @@ impl ME {
                 writeln!(f, "  Address:      {icao} {address_type}")?;
                 write!(f, "{altitude}")?;
             }
-            ME::Reserved0(_) | ME::Reserved1 { .. } => {
+            ME::Reserved1 { .. } => {
                 writeln!(f, " Extended Squitter{transponder}Unknown")?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
             }
-            ME::SurfaceSystemStatus(_) => {
+            ME::TestMessage(test) => {
+                writeln!(f, " Extended Squitter{transponder}Test message")?;
+                writeln!(f, "  Address:       {icao} {address_type}")?;
+                writeln!(f, "  Air/Ground:    {capability}")?;
+                match test {
+                    TestMessage::Squawk { squawk } => writeln!(f, "  Squawk:        {squawk:04x}")?,
+                    TestMessage::Raw(subtype, _) => writeln!(f, "  Subtype:       {subtype}")?,
+                }
+            }
+            ME::SurfaceSystemStatus(status) => {
                 writeln!(f, " Extended Squitter{transponder}Reserved for surface system status",)?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
+                writeln!(f, "  Subtype:       {}", status.subtype)?;
             }
-            ME::AircraftStatus(AircraftStatus { emergency_state, squawk, .. }) => {
+            ME::AircraftStatus(AircraftStatus::EmergencyPriorityStatus(
+                EmergencyPriorityStatus { emergency_state, squawk },
+            )) => {
                 writeln!(f, " Extended Squitter{transponder}Emergency/priority status",)?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
                 writeln!(f, "  Squawk:        {squawk:x?}")?;
                 writeln!(f, "  Emergency/priority:    {emergency_state}")?;
             }
-            ME::TargetStateAndStatusInformation(target_info) => {
+            ME::AircraftStatus(AircraftStatus::ACASRaBroadcast(ra)) => {
+                writeln!(f, " Extended Squitter{transponder}ACAS resolution advisory broadcast",)?;
+                writeln!(f, "  Address:       {icao} {address_type}")?;
+                writeln!(f, "  Air/Ground:    {capability}")?;
+                write!(f, "{ra}")?;
+            }
+            ME::AircraftStatus(AircraftStatus::Reserved(..)) => {
+                writeln!(f, " Extended Squitter{transponder}Aircraft status (reserved)",)?;
+                writeln!(f, "  Address:       {icao} {address_type}")?;
+            }
+            ME::TargetStateAndStatusInformation(TargetStateAndStatus::Version1(target_info)) => {
+                writeln!(f, " Extended Squitter{transponder}Target state and status (V1)",)?;
+                writeln!(f, "  Address:       {icao} {address_type}")?;
+                writeln!(f, "  Air/Ground:    {capability}")?;
+                writeln!(f, "  Target State and Status:")?;
+                if let Some(altitude) = target_info.altitude {
+                    writeln!(
+                        f,
+                        "    Target altitude:   {}, {altitude} ft ({})",
+                        target_info.vertical_source, target_info.altitude_type
+                    )?;
+                }
+                writeln!(f, "    Vertical mode:     {}", target_info.vertical_mode)?;
+                if target_info.horizontal_source != HorizontalDataSource::NoValidData {
+                    let label =
+                        if target_info.is_track { "Target track:" } else { "Target heading:" };
+                    writeln!(
+                        f,
+                        "    {label:<19}{}, {}",
+                        target_info.horizontal_source, target_info.heading
+                    )?;
+                }
+                writeln!(f, "    Horizontal mode:   {}", target_info.horizontal_mode)?;
+                if target_info.tcas_not_operational {
+                    writeln!(f, "    ACAS:              NOT operational")?;
+                } else if target_info.tcas_ra_active {
+                    writeln!(f, "    ACAS:              operational, RA active")?;
+                } else {
+                    writeln!(f, "    ACAS:              operational")?;
+                }
+                writeln!(f, "    NACp:              {}", target_info.nacp)?;
+                writeln!(f, "    NICbaro:           {}", target_info.nicbaro)?;
+                writeln!(f, "    SIL:               {}", target_info.sil)?;
+                writeln!(f, "    Emergency/priority: {}", target_info.emergency_state)?;
+            }
+            ME::TargetStateAndStatusInformation(TargetStateAndStatus::Version2(target_info)) => {
                 writeln!(f, " Extended Squitter{transponder}Target state and status (V2)",)?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
@@ -255,9 +338,21 @@ impl ME {
                 writeln!(f, "    SIL:               {} (per sample)", target_info.sil)?;
                 writeln!(f, "    QNH:               {} millibars", target_info.qnh)?;
             }
-            ME::AircraftOperationalCoordination(_) => {
+            ME::TargetStateAndStatusInformation(TargetStateAndStatus::Reserved(..)) => {
+                writeln!(f, " Extended Squitter{transponder}Target state and status (reserved)",)?;
+                writeln!(f, "  Address:       {icao} {address_type}")?;
+            }
+            ME::AircraftOperationalCoordination(coordination) => {
                 writeln!(f, " Extended Squitter{transponder}Aircraft Operational Coordination",)?;
                 writeln!(f, "  Address:       {icao} {address_type}")?;
+                match coordination {
+                    OperationalCoordination::PairedAddress { paired_address, .. } => {
+                        writeln!(f, "  Paired with:   {paired_address}")?;
+                    }
+                    OperationalCoordination::Raw(subtype, _) => {
+                        writeln!(f, "  Subtype:       {subtype}")?;
+                    }
+                }
             }
             ME::AircraftOperationStatus(OperationStatus::Airborne(opstatus_airborne)) => {
                 writeln!(
@@ -289,8 +384,16 @@ impl ME {
     }
 }
 
+fn sonic(airborne_velocity: &AirborneVelocity) -> &'static str {
+    if matches!(airborne_velocity.st, 2 | 4) {
+        "supersonic"
+    } else {
+        "subsonic"
+    }
+}
+
 /// [`ME::AirborneVelocity`] && [`AirborneVelocitySubType::GroundSpeedDecoding`]
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroundSpeedDecoding {
     pub ew_sign: Sign,
@@ -302,7 +405,7 @@ pub struct GroundSpeedDecoding {
 }
 
 /// [`ME::AirborneVelocity`] && [`AirborneVelocitySubType::AirspeedDecoding`]
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirspeedDecoding {
     #[deku(bits = "1")]
@@ -311,16 +414,35 @@ pub struct AirspeedDecoding {
     pub mag_heading: u16,
     #[deku(bits = "1")]
     pub airspeed_type: u8,
+    /// Airspeed in knots, `None` if unavailable
     #[deku(
         endian = "big",
         bits = "10",
-        map = "|airspeed: u16| -> result::Result<_, DekuError> {Ok(if airspeed > 0 { airspeed - 1 } else { 0 })}"
+        map = "|airspeed: u16| -> result::Result<_, DekuError> {Ok(airspeed.checked_sub(1))}",
+        writer = "Self::write_airspeed(deku::writer, *airspeed)"
     )]
-    pub airspeed: u16,
+    pub airspeed: Option<u16>,
+}
+
+impl AirspeedDecoding {
+    fn write_airspeed<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        airspeed: Option<u16>,
+    ) -> result::Result<(), DekuError> {
+        let raw = match airspeed {
+            None => 0,
+            Some(airspeed) => {
+                airspeed.checked_add(1).filter(|raw| *raw < 1 << 10).ok_or_else(|| {
+                    DekuError::InvalidParam("airspeed does not fit in 10 bits".into())
+                })?
+            }
+        };
+        raw.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(10)))
+    }
 }
 
 /// Aircraft Operational Status Subtype
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 pub enum OperationStatus {
@@ -330,14 +452,157 @@ pub enum OperationStatus {
     #[deku(id = "1")]
     Surface(OperationStatusSurface),
 
+    /// Reserved in every version, kept as the subtype and the raw 45 bits after it
     #[deku(id_pat = "2..=7")]
-    Reserved(u8, [u8; 4]),
+    Reserved(u8, [u8; 6]),
+}
+
+impl OperationStatus {
+    #[must_use]
+    pub fn version(&self) -> Option<ADSBVersion> {
+        match self {
+            Self::Airborne(airborne) => Some(airborne.version_number),
+            Self::Surface(surface) => Some(surface.version_number),
+            Self::Reserved(..) => None,
+        }
+    }
+
+    /// Accuracy and integrity fields, as far as the [`ADSBVersion`] of the message defines them
+    #[must_use]
+    pub fn integrity(&self) -> Option<Integrity> {
+        let (version, operational_mode) = match self {
+            Self::Airborne(airborne) => (airborne.version_number, airborne.operational_mode),
+            Self::Surface(surface) => (surface.version_number, surface.operational_mode),
+            Self::Reserved(..) => return None,
+        };
+        // version 0 (DO-260) only defines the capability class and operational mode
+        if version == ADSBVersion::DOC9871AppendixA {
+            return Some(Integrity::default());
+        }
+        let v2 = version == ADSBVersion::DOC9871AppendixC;
+        let (nic_supplement_a, nacp, sil, sil_supplement, hrd) = match self {
+            Self::Airborne(a) => (
+                a.nic_supplement_a,
+                a.navigational_accuracy_category,
+                a.source_integrity_level,
+                a.sil_supplement,
+                a.horizontal_reference_direction,
+            ),
+            Self::Surface(s) => (
+                s.nic_supplement_a,
+                s.navigational_accuracy_category,
+                s.source_integrity_level,
+                s.sil_supplement,
+                s.horizontal_reference_direction,
+            ),
+            Self::Reserved(..) => return None,
+        };
+        let mut integrity = Integrity {
+            nic_supplement_a: Some(nic_supplement_a == 1),
+            nacp: Some(nacp),
+            sil: Some(sil),
+            sil_per_sample: v2.then_some(sil_supplement == 1),
+            sda: v2.then_some(operational_mode.system_design_assurance),
+            hrd: Some(if hrd == 1 {
+                HeadingReference::MagneticNorth
+            } else {
+                HeadingReference::TrueNorth
+            }),
+            ..Integrity::default()
+        };
+        match self {
+            Self::Airborne(airborne) => {
+                // BAQ in version 1, always 0
+                integrity.gva = v2.then_some(airborne.geometric_vertical_accuracy);
+                integrity.nic_baro = Some(airborne.barometric_altitude_integrity == 1);
+            }
+            Self::Surface(surface) => {
+                integrity.nic_supplement_c =
+                    v2.then_some(surface.capability_class.nic_supplement_c == 1);
+                integrity.tah = Some(if surface.track_angle_heading == 1 {
+                    TrackAngleHeading::Heading
+                } else {
+                    TrackAngleHeading::TrackAngle
+                });
+            }
+            Self::Reserved(..) => (),
+        }
+        Some(integrity)
+    }
+}
+
+/// Accuracy and integrity of [`OperationStatus`], `None` where its version doesn't define them
+///
+/// See [`crate::accuracy::position_accuracy`] for the containment radius of the NIC supplements.
+#[derive(Debug, PartialEq, Eq, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Integrity {
+    pub nic_supplement_a: Option<bool>,
+    /// Surface only, version 2
+    pub nic_supplement_c: Option<bool>,
+    /// NACp: Navigational Accuracy Category for Position, see [`crate::accuracy::epu`]
+    pub nacp: Option<u8>,
+    /// GVA: Geometric Vertical Accuracy, airborne only, version 2
+    pub gva: Option<u8>,
+    /// SIL: Source Integrity Level
+    pub sil: Option<u8>,
+    /// SIL supplement, the probability of [`Self::sil`] is per sample instead of per hour
+    pub sil_per_sample: Option<bool>,
+    /// SDA: System Design Assurance, version 2
+    pub sda: Option<u8>,
+    /// NICbaro, airborne only
+    pub nic_baro: Option<bool>,
+    /// HRD: Horizontal Reference Direction
+    pub hrd: Option<HeadingReference>,
+    /// TAH: Track Angle/Heading of surface positions, surface only
+    pub tah: Option<TrackAngleHeading>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeadingReference {
+    TrueNorth,
+    MagneticNorth,
+}
+
+impl fmt::Display for HeadingReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::TrueNorth => "true north",
+                Self::MagneticNorth => "magnetic north",
+            }
+        )
+    }
+}
+
+/// What the [`SurfacePosition::trk`] field holds
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackAngleHeading {
+    TrackAngle,
+    Heading,
+}
+
+impl fmt::Display for TrackAngleHeading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::TrackAngle => "track angle",
+                Self::Heading => "heading",
+            }
+        )
+    }
 }
 
 /// [`ME::AircraftOperationStatus`] && [`OperationStatus`] == 0
 ///
-/// Version 2 support only
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+/// See [`OperationStatus::integrity`] for the fields defined by each version
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationStatusAirborne {
     /// CC (16 bits)
@@ -377,22 +642,15 @@ impl fmt::Display for OperationStatusAirborne {
         writeln!(f, "   Version:            {}", self.version_number)?;
         writeln!(f, "   Capability classes:{}", self.capability_class)?;
         writeln!(f, "   Operational modes: {}", self.operational_mode)?;
-        writeln!(f, "   NIC-A:              {}", self.nic_supplement_a)?;
-        writeln!(f, "   NACp:               {}", self.navigational_accuracy_category)?;
-        writeln!(f, "   GVA:                {}", self.geometric_vertical_accuracy)?;
-        writeln!(f, "   SIL:                {} (per hour)", self.source_integrity_level)?;
-        writeln!(f, "   NICbaro:            {}", self.barometric_altitude_integrity)?;
-        if self.horizontal_reference_direction == 1 {
-            writeln!(f, "   Heading reference:  magnetic north")?;
-        } else {
-            writeln!(f, "   Heading reference:  true north")?;
+        if let Some(integrity) = OperationStatus::Airborne(*self).integrity() {
+            write!(f, "{integrity}")?;
         }
         Ok(())
     }
 }
 
 /// [`ME::AircraftOperationStatus`]
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityClassAirborne {
     #[deku(bits = "2", assert_eq = "0")]
@@ -443,14 +701,14 @@ impl fmt::Display for CapabilityClassAirborne {
 
 /// [`ME::AircraftOperationStatus`] && [`OperationStatus`] == 1
 ///
-/// Version 2 support only
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+/// See [`OperationStatus::integrity`] for the fields defined by each version
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationStatusSurface {
     /// CC (14 bits)
     pub capability_class: CapabilityClassSurface,
 
-    /// CC L/W codes
+    /// CC L/W codes, see [`Self::length_width`]
     #[deku(bits = "4")]
     pub lw_codes: u8,
 
@@ -458,10 +716,7 @@ pub struct OperationStatusSurface {
     pub operational_mode: OperationalMode,
 
     /// OM last 8 bits (diff for airborne/surface)
-    // TODO: parse:
-    // http://www.anteni.net/adsb/Doc/1090-WP30-18-DRAFT_DO-260B-V42.pdf
-    // 2.2.3.2.7.2.4.7 “GPS Antenna Offset” OM Code Subfield in Aircraft Operational Status Messages
-    pub gps_antenna_offset: u8,
+    pub gps_antenna_offset: GpsAntennaOffset,
 
     pub version_number: ADSBVersion,
 
@@ -475,8 +730,9 @@ pub struct OperationStatusSurface {
     #[deku(bits = "2")]
     pub source_integrity_level: u8,
 
+    /// TAH: 0 if [`SurfacePosition::trk`] is the track angle, 1 if it is the heading
     #[deku(bits = "1")]
-    pub barometric_altitude_integrity: u8,
+    pub track_angle_heading: u8,
 
     #[deku(bits = "1")]
     pub horizontal_reference_direction: u8,
@@ -486,10 +742,41 @@ pub struct OperationStatusSurface {
     pub sil_supplement: u8,
 }
 
+impl OperationStatusSurface {
+    /// Upper bounds of the aircraft (length, width) in meters of [`Self::lw_codes`]
+    ///
+    /// reference: DO-260B Table 2-74
+    #[must_use]
+    pub fn length_width(&self) -> Option<(f32, f32)> {
+        const LENGTH_WIDTH: [(f32, f32); 15] = [
+            (15.0, 23.0),
+            (25.0, 28.5),
+            (25.0, 34.0),
+            (35.0, 33.0),
+            (35.0, 38.0),
+            (45.0, 39.5),
+            (45.0, 45.0),
+            (55.0, 45.0),
+            (55.0, 52.0),
+            (65.0, 59.5),
+            (65.0, 67.0),
+            (75.0, 72.5),
+            (75.0, 80.0),
+            (85.0, 80.0),
+            (85.0, 90.0),
+        ];
+        let code = usize::from(self.lw_codes.checked_sub(1)?);
+        LENGTH_WIDTH.get(code).copied()
+    }
+}
+
 impl fmt::Display for OperationStatusSurface {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let integrity = OperationStatus::Surface(*self).integrity().unwrap_or_default();
         writeln!(f, "  Version:            {}", self.version_number)?;
-        writeln!(f, "   NIC-A:              {}", self.nic_supplement_a)?;
+        if let Some(nic_supplement_a) = integrity.nic_supplement_a {
+            writeln!(f, "   NIC-A:              {}", u8::from(nic_supplement_a))?;
+        }
         write!(f, "{}", self.capability_class)?;
         write!(f, "   Capability classes:")?;
         if self.lw_codes != 0 {
@@ -497,22 +784,104 @@ impl fmt::Display for OperationStatusSurface {
         } else {
             writeln!(f)?;
         }
+        if let Some((length, width)) = self.length_width() {
+            writeln!(f, "   Length/width:       < {length} m / < {width} m")?;
+        }
         write!(f, "   Operational modes: {}", self.operational_mode)?;
         writeln!(f)?;
-        writeln!(f, "   NACp:               {}", self.navigational_accuracy_category)?;
-        writeln!(f, "   SIL:                {} (per hour)", self.source_integrity_level)?;
-        writeln!(f, "   NICbaro:            {}", self.barometric_altitude_integrity)?;
-        if self.horizontal_reference_direction == 1 {
-            writeln!(f, "   Heading reference:  magnetic north")?;
-        } else {
-            writeln!(f, "   Heading reference:  true north")?;
+        if self.gps_antenna_offset != GpsAntennaOffset::default() {
+            writeln!(f, "   GPS antenna:        {}", self.gps_antenna_offset)?;
+        }
+        let integrity = Integrity { nic_supplement_a: None, ..integrity };
+        write!(f, "{integrity}")
+    }
+}
+
+/// GPS antenna offset of [`OperationStatusSurface`]
+///
+/// reference: DO-260B 2.2.3.2.7.2.4.7
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpsAntennaOffset {
+    /// Direction bit and 2 bits of distance from the longitudinal axis, see [`Self::lateral`]
+    #[deku(bits = "3")]
+    pub lateral_code: u8,
+    /// Distance aft of the nose, see [`Self::longitudinal`]
+    #[deku(bits = "5")]
+    pub longitudinal_code: u8,
+}
+
+impl GpsAntennaOffset {
+    /// Meters right of the longitudinal axis, negative for left, `None` if unavailable
+    #[must_use]
+    pub fn lateral(&self) -> Option<i8> {
+        let distance = (self.lateral_code & 0b11) as i8 * 2;
+        match (self.lateral_code & 0b100 != 0, distance) {
+            (false, 0) => None,
+            (false, distance) => Some(-distance),
+            (true, distance) => Some(distance),
+        }
+    }
+
+    /// Meters aft of the nose, `None` if unavailable
+    ///
+    /// `Some(0)` if the position offset is applied by the sensor, so it is already relative to
+    /// the nose.
+    #[must_use]
+    pub fn longitudinal(&self) -> Option<u8> {
+        match self.longitudinal_code {
+            0 => None,
+            1 => Some(0),
+            code => Some((code - 1) * 2),
+        }
+    }
+}
+
+impl fmt::Display for GpsAntennaOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lateral() {
+            None => write!(f, "lateral unknown")?,
+            Some(lateral) if lateral < 0 => write!(f, "{} m left", -lateral)?,
+            Some(lateral) => write!(f, "{lateral} m right")?,
+        }
+        match self.longitudinal() {
+            None => write!(f, ", longitudinal unknown"),
+            Some(0) if self.longitudinal_code == 1 => write!(f, ", applied by sensor"),
+            Some(longitudinal) => write!(f, ", {longitudinal} m aft of nose"),
+        }
+    }
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(nic_supplement_a) = self.nic_supplement_a {
+            writeln!(f, "   NIC-A:              {}", u8::from(nic_supplement_a))?;
+        }
+        if let Some(nacp) = self.nacp {
+            writeln!(f, "   NACp:               {nacp}")?;
+        }
+        if let Some(gva) = self.gva {
+            writeln!(f, "   GVA:                {gva}")?;
+        }
+        if let Some(sil) = self.sil {
+            let per = if self.sil_per_sample == Some(true) { "sample" } else { "hour" };
+            writeln!(f, "   SIL:                {sil} (per {per})")?;
+        }
+        if let Some(nic_baro) = self.nic_baro {
+            writeln!(f, "   NICbaro:            {}", u8::from(nic_baro))?;
+        }
+        if let Some(tah) = self.tah {
+            writeln!(f, "   Track/heading:      {tah}")?;
+        }
+        if let Some(hrd) = self.hrd {
+            writeln!(f, "   Heading reference:  {hrd}")?;
         }
         Ok(())
     }
 }
 
 /// [`ME::AircraftOperationStatus`]
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityClassSurface {
     /// 0, 0 in current version, reserved as id for later versions
@@ -554,27 +923,27 @@ impl fmt::Display for CapabilityClassSurface {
 }
 
 /// `OperationMode` field not including the last 8 bits that are different for Surface/Airborne
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationalMode {
     /// (0, 0) in Version 2, reserved for other values
     #[deku(bits = "2", assert_eq = "0")]
-    reserved: u8,
+    pub reserved: u8,
 
     #[deku(bits = "1")]
-    tcas_ra_active: bool,
+    pub tcas_ra_active: bool,
 
     #[deku(bits = "1")]
-    ident_switch_active: bool,
+    pub ident_switch_active: bool,
 
     #[deku(bits = "1")]
-    reserved_recv_atc_service: bool,
+    pub reserved_recv_atc_service: bool,
 
     #[deku(bits = "1")]
-    single_antenna_flag: bool,
+    pub single_antenna_flag: bool,
 
     #[deku(bits = "2")]
-    system_design_assurance: u8,
+    pub system_design_assurance: u8,
 }
 
 impl fmt::Display for OperationalMode {
@@ -601,7 +970,7 @@ impl fmt::Display for OperationalMode {
 /// ADS-B Defined from different ICAO documents
 ///
 /// reference: ICAO 9871 (5.3.2.3)
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 pub enum ADSBVersion {
@@ -622,10 +991,11 @@ impl fmt::Display for ADSBVersion {
 /// Control Field (B.3) for [`crate::DF::TisB`]
 ///
 /// reference: ICAO 9871
-#[derive(Debug, PartialEq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ControlField {
-    t: ControlFieldType,
+    /// CF: Control Field type
+    pub t: ControlFieldType,
     /// AA: Address, Announced
     pub aa: ICAO,
     /// ME: message, extended quitter
@@ -642,7 +1012,42 @@ impl fmt::Display for ControlField {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+/// AF: Application Field of [`crate::DF::ExtendedQuitterMilitaryApplication`]
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum MilitaryApplication {
+    /// AF=0: extended squitter with the layout of [`crate::DF::ADSB`]
+    #[deku(id = "0")]
+    ExtendedSquitter {
+        /// AA: Address, Announced
+        icao: ICAO,
+        /// ME: message, extended squitter
+        me: ME,
+        /// PI: parity/interrogator identifier
+        pi: ICAO,
+    },
+
+    /// AF=1..=7, kept as the AF and the raw 104 bits after it
+    #[deku(id_pat = "1..=7")]
+    Raw(u8, [u8; 13]),
+}
+
+impl fmt::Display for MilitaryApplication {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExtendedSquitter { icao, me, .. } => {
+                write!(f, "{}", me.to_string(*icao, "(Military)", Capability::AG_UNCERTAIN3, true)?)
+            }
+            Self::Raw(af, _) => {
+                writeln!(f, " Extended Squitter Military Application")?;
+                writeln!(f, "  AF:            {af}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 #[allow(non_camel_case_types)]
@@ -694,35 +1099,97 @@ impl fmt::Display for ControlFieldType {
     }
 }
 
+/// Aircraft Status Subtype
+///
 /// Table: A-2-97
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AircraftStatus {
-    pub sub_type: AircraftStatusType,
+#[deku(id_type = "u8", bits = "3")]
+pub enum AircraftStatus {
+    #[deku(id = "1")]
+    EmergencyPriorityStatus(EmergencyPriorityStatus),
+
+    /// Resolution advisory broadcast by ACAS
+    #[deku(id = "2")]
+    ACASRaBroadcast(ResolutionAdvisory),
+
+    /// No information, or reserved
+    #[deku(id_pat = "0 | 3..=7")]
+    Reserved(u8, [u8; 6]),
+}
+
+/// [`ME::TestMessage`] Subtype
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum TestMessage {
+    /// Mode A code, broadcast by some avionics in place of the emergency/priority status
+    ///
+    /// reference: 1090-WP-15-20
+    #[deku(id = "7")]
+    Squawk {
+        #[deku(
+            bits = "13",
+            endian = "big",
+            map = "|squawk: u32| -> Result<_, DekuError> {Ok(decode_id13_field(squawk))}",
+            writer = "encode_id13_field(*squawk).to_writer(deku::writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(13)))",
+            pad_bits_after = "35"
+        )]
+        squawk: u32,
+    },
+
+    /// Subtype 0 has no defined layout, the others are reserved
+    #[deku(id_pat = "0..=6")]
+    Raw(u8, [u8; 6]),
+}
+
+/// [`ME::SurfaceSystemStatus`]
+///
+/// DO-260B reserves the layout after the subtype, so it is kept as raw bytes
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurfaceSystemStatus {
+    #[deku(bits = "3")]
+    pub subtype: u8,
+    pub data: [u8; 6],
+}
+
+/// [`ME::AircraftOperationalCoordination`] Subtype
+///
+/// Only defined by DO-260 (version 0), and reserved since
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum OperationalCoordination {
+    /// Address of the aircraft this one is paired with, such as for parallel approaches
+    #[deku(id = "0")]
+    PairedAddress {
+        paired_address: ICAO,
+        /// Remaining bits, reserved
+        reserved: [u8; 3],
+    },
+
+    /// Reserved subtypes
+    #[deku(id_pat = "1..=7")]
+    Raw(u8, [u8; 6]),
+}
+
+/// [`ME::AircraftStatus`] && [`AircraftStatus`] == 1
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmergencyPriorityStatus {
     pub emergency_state: EmergencyState,
     #[deku(
         bits = "13",
         endian = "big",
-        map = "|squawk: u32| -> Result<_, DekuError> {Ok(decode_id13_field(squawk))}"
+        map = "|squawk: u32| -> Result<_, DekuError> {Ok(decode_id13_field(squawk))}",
+        writer = "encode_id13_field(*squawk).to_writer(deku::writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(13)))",
+        pad_bits_after = "32"
     )]
     pub squawk: u32,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[deku(id_type = "u8", bits = "3")]
-pub enum AircraftStatusType {
-    #[deku(id = "0")]
-    NoInformation,
-    #[deku(id = "1")]
-    EmergencyPriorityStatus,
-    #[deku(id = "2")]
-    ACASRaBroadcast,
-    #[deku(id_pat = "_")]
-    Reserved(u8),
-}
-
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 pub enum EmergencyState {
@@ -753,7 +1220,7 @@ impl fmt::Display for EmergencyState {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationCodeSurface {
     #[deku(bits = "1")]
@@ -767,22 +1234,25 @@ pub struct OperationCodeSurface {
     pub lw: u8,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(ctx = "id: u8")]
 pub struct Identification {
-    #[deku(reader = "TypeCoding::custom_read(id)")]
+    #[deku(reader = "TypeCoding::custom_read(id)", writer = "tc.custom_write(id)")]
     pub tc: TypeCoding,
 
     #[deku(bits = "3")]
     pub ca: u8,
 
     /// N-Number / Tail Number
-    #[deku(reader = "aircraft_identification_read(deku::reader)")]
-    pub cn: String,
+    #[deku(
+        reader = "aircraft_identification_read(deku::reader)",
+        writer = "aircraft_identification_write(deku::writer, cn)"
+    )]
+    pub cn: Callsign,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "8")]
 pub enum TypeCoding {
@@ -797,6 +1267,15 @@ impl TypeCoding {
         let (_, res) = Self::from_bytes((&[id], 0))?;
         Ok(res)
     }
+
+    /// Already written as the [`ME`] type code, only check that they match
+    fn custom_write(&self, id: u8) -> Result<(), DekuError> {
+        if *self as u8 == id {
+            Ok(())
+        } else {
+            Err(DekuError::InvalidParam("TypeCoding does not match ME type code".into()))
+        }
+    }
 }
 
 impl fmt::Display for TypeCoding {
@@ -814,26 +1293,299 @@ impl fmt::Display for TypeCoding {
     }
 }
 
+impl Identification {
+    /// Emitter category of [`Self::tc`] and [`Self::ca`]
+    #[must_use]
+    pub fn emitter_category(&self) -> EmitterCategory {
+        EmitterCategory::new(self.tc, self.ca)
+    }
+}
+
+/// Emitter category, from the [`TypeCoding`] set and the category within it
+///
+/// reference: DO-260B Table 2-8
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmitterCategory {
+    /// Category 0 of sets A, B and C
+    NoInformation,
+    /// Light, less than 15500 lbs
+    Light,
+    /// Small, 15500 to 75000 lbs
+    Small,
+    /// Large, 75000 to 300000 lbs
+    Large,
+    /// High vortex large, such as the B757
+    HighVortexLarge,
+    /// Heavy, more than 300000 lbs
+    Heavy,
+    /// High performance, more than 5g acceleration and 400 kt
+    HighPerformance,
+    Rotorcraft,
+    Glider,
+    LighterThanAir,
+    Parachutist,
+    Ultralight,
+    UnmannedAerialVehicle,
+    SpaceVehicle,
+    SurfaceEmergencyVehicle,
+    SurfaceServiceVehicle,
+    PointObstacle,
+    ClusterObstacle,
+    LineObstacle,
+    /// Every category of set D, and categories 5 of set B and 6..=7 of set C
+    Reserved {
+        tc: TypeCoding,
+        ca: u8,
+    },
+}
+
+impl EmitterCategory {
+    #[must_use]
+    pub fn new(tc: TypeCoding, ca: u8) -> Self {
+        match (tc, ca) {
+            (TypeCoding::A | TypeCoding::B | TypeCoding::C, 0) => Self::NoInformation,
+            (TypeCoding::A, 1) => Self::Light,
+            (TypeCoding::A, 2) => Self::Small,
+            (TypeCoding::A, 3) => Self::Large,
+            (TypeCoding::A, 4) => Self::HighVortexLarge,
+            (TypeCoding::A, 5) => Self::Heavy,
+            (TypeCoding::A, 6) => Self::HighPerformance,
+            (TypeCoding::A, 7) => Self::Rotorcraft,
+            (TypeCoding::B, 1) => Self::Glider,
+            (TypeCoding::B, 2) => Self::LighterThanAir,
+            (TypeCoding::B, 3) => Self::Parachutist,
+            (TypeCoding::B, 4) => Self::Ultralight,
+            (TypeCoding::B, 6) => Self::UnmannedAerialVehicle,
+            (TypeCoding::B, 7) => Self::SpaceVehicle,
+            (TypeCoding::C, 1) => Self::SurfaceEmergencyVehicle,
+            (TypeCoding::C, 2) => Self::SurfaceServiceVehicle,
+            (TypeCoding::C, 3) => Self::PointObstacle,
+            (TypeCoding::C, 4) => Self::ClusterObstacle,
+            (TypeCoding::C, 5) => Self::LineObstacle,
+            (tc, ca) => Self::Reserved { tc, ca },
+        }
+    }
+}
+
+impl fmt::Display for EmitterCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::NoInformation => "no information",
+                Self::Light => "light",
+                Self::Small => "small",
+                Self::Large => "large",
+                Self::HighVortexLarge => "high vortex large",
+                Self::Heavy => "heavy",
+                Self::HighPerformance => "high performance",
+                Self::Rotorcraft => "rotorcraft",
+                Self::Glider => "glider",
+                Self::LighterThanAir => "lighter than air",
+                Self::Parachutist => "parachutist",
+                Self::Ultralight => "ultralight",
+                Self::UnmannedAerialVehicle => "UAV",
+                Self::SpaceVehicle => "space vehicle",
+                Self::SurfaceEmergencyVehicle => "surface emergency vehicle",
+                Self::SurfaceServiceVehicle => "surface service vehicle",
+                Self::PointObstacle => "point obstacle",
+                Self::ClusterObstacle => "cluster obstacle",
+                Self::LineObstacle => "line obstacle",
+                Self::Reserved { .. } => "reserved",
+            }
+        )
+    }
+}
+
+/// Target State and Status Subtype
+#[derive(Copy, Clone, Debug, PartialEq, DekuRead, DekuWrite)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "2")]
+pub enum TargetStateAndStatus {
+    /// ADS-B Version 1 (DO-260A)
+    #[deku(id = "0")]
+    Version1(TargetStateAndStatusVersion1),
+
+    /// ADS-B Version 2 (DO-260B)
+    #[deku(id = "1")]
+    Version2(TargetStateAndStatusInformation),
+
+    #[deku(id_pat = "2..=3")]
+    Reserved(u8, #[deku(bits = "49", endian = "big")] u64),
+}
+
+/// [`ME::TargetStateAndStatusInformation`] && [`TargetStateAndStatus`] == 0
+///
+/// reference: DO-260A (§2.2.3.2.7.1)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetStateAndStatusVersion1 {
+    pub vertical_source: VerticalDataSource,
+    pub altitude_type: TargetAltitudeType,
+    #[deku(bits = "1")]
+    pub backward_compatibility: bool,
+    /// 0: holding altitude, 1: holding or autopilot selected altitude, 2: holding, autopilot or
+    /// FMS selected altitude, 3: reserved
+    #[deku(bits = "2")]
+    pub altitude_capability: u8,
+    pub vertical_mode: TargetModeIndicator,
+    /// Target altitude (ft), `None` if invalid
+    #[deku(
+        reader = "Self::read_altitude(deku::reader)",
+        writer = "Self::write_altitude(deku::writer, *altitude)"
+    )]
+    pub altitude: Option<i32>,
+    pub horizontal_source: HorizontalDataSource,
+    /// Target heading or track angle (degrees)
+    #[deku(bits = "9", endian = "big")]
+    pub heading: u16,
+    /// `heading` is a track angle, otherwise a heading
+    #[deku(bits = "1")]
+    pub is_track: bool,
+    pub horizontal_mode: TargetModeIndicator,
+    #[deku(bits = "4")]
+    pub nacp: u8,
+    #[deku(bits = "1")]
+    pub nicbaro: u8,
+    #[deku(bits = "2")]
+    #[deku(pad_bits_after = "5")] // reserved
+    pub sil: u8,
+    /// ACAS not operational, otherwise operational or unknown
+    #[deku(bits = "1")]
+    pub tcas_not_operational: bool,
+    #[deku(bits = "1")]
+    pub tcas_ra_active: bool,
+    pub emergency_state: EmergencyState,
+}
+
+impl TargetStateAndStatusVersion1 {
+    fn read_altitude<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<i32>, DekuError> {
+        let altitude =
+            u16::from_reader_with_ctx(reader, (deku::ctx::Endian::Big, deku::ctx::BitSize(10)))?;
+        Ok((altitude <= 1010).then(|| i32::from(altitude) * 100 - 1000))
+    }
+
+    fn write_altitude<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        altitude: Option<i32>,
+    ) -> result::Result<(), DekuError> {
+        let altitude = altitude.map_or(1023, |altitude| ((altitude + 1000) / 100) as u16);
+        altitude.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(10)))
+    }
+}
+
+/// Vertical data available / source indicator of [`TargetStateAndStatusVersion1`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "2")]
+pub enum VerticalDataSource {
+    NoValidData = 0,
+    Autopilot = 1,
+    HoldingAltitude = 2,
+    FMS = 3,
+}
+
+impl fmt::Display for VerticalDataSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::NoValidData => "no valid data",
+            Self::Autopilot => "MCP/FCU",
+            Self::HoldingAltitude => "holding altitude",
+            Self::FMS => "FMS/RNAV",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Horizontal data available / source indicator of [`TargetStateAndStatusVersion1`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "2")]
+pub enum HorizontalDataSource {
+    NoValidData = 0,
+    Autopilot = 1,
+    MaintainingHeading = 2,
+    FMS = 3,
+}
+
+impl fmt::Display for HorizontalDataSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::NoValidData => "no valid data",
+            Self::Autopilot => "MCP/FCU",
+            Self::MaintainingHeading => "maintaining current heading/track",
+            Self::FMS => "FMS/RNAV",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Target altitude type of [`TargetStateAndStatusVersion1`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "1")]
+pub enum TargetAltitudeType {
+    FlightLevel = 0,
+    MeanSeaLevel = 1,
+}
+
+impl fmt::Display for TargetAltitudeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::FlightLevel => "FL",
+            Self::MeanSeaLevel => "MSL",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Vertical and horizontal mode indicator of [`TargetStateAndStatusVersion1`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "2")]
+pub enum TargetModeIndicator {
+    Unknown = 0,
+    Acquiring = 1,
+    CapturingOrMaintaining = 2,
+    Reserved = 3,
+}
+
+impl fmt::Display for TargetModeIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Unknown => "unknown",
+            Self::Acquiring => "acquiring",
+            Self::CapturingOrMaintaining => "capturing/maintaining",
+            Self::Reserved => "reserved",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// [`ME::TargetStateAndStatusInformation`] && [`TargetStateAndStatus`] == 1
+///
 /// Target State and Status (§2.2.3.2.7.1)
-#[derive(Copy, Clone, Debug, PartialEq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TargetStateAndStatusInformation {
-    // TODO Support Target State and Status defined in DO-260A, ADS-B Version=1
-    // TODO Support reserved 2..=3
-    #[deku(bits = "2")]
-    pub subtype: u8,
     #[deku(bits = "1")]
     pub is_fms: bool,
     #[deku(
         bits = "12",
         endian = "big",
-        map = "|altitude: u32| -> Result<_, DekuError> {Ok(if altitude > 1 {(altitude - 1) * 32} else {0} )}"
+        map = "|altitude: u32| -> Result<_, DekuError> {Ok(if altitude > 1 {(altitude - 1) * 32} else {0} )}",
+        writer = "Self::write_altitude(deku::writer, *altitude)"
     )]
     pub altitude: u32,
     #[deku(
         bits = "9",
         endian = "big",
-        map = "|qnh: u32| -> Result<_, DekuError> {if qnh == 0 { Ok(0.0) } else { Ok(800.0 + ((qnh - 1) as f32) * 0.8)}}"
+        map = "|qnh: u32| -> Result<_, DekuError> {if qnh == 0 { Ok(0.0) } else { Ok(800.0 + ((qnh - 1) as f32) * 0.8)}}",
+        writer = "Self::write_qnh(deku::writer, *qnh)"
     )]
     pub qnh: f32,
     #[deku(bits = "1")]
@@ -841,7 +1593,8 @@ pub struct TargetStateAndStatusInformation {
     #[deku(
         bits = "9",
         endian = "big",
-        map = "|heading: u16| -> Result<_, DekuError> {Ok(heading as f32 * 180.0 / 256.0)}"
+        map = "|heading: u16| -> Result<_, DekuError> {Ok(heading as f32 * 180.0 / 256.0)}",
+        writer = "Self::write_heading(deku::writer, *heading)"
     )]
     pub heading: f32,
     #[deku(bits = "4")]
@@ -869,8 +1622,34 @@ pub struct TargetStateAndStatusInformation {
     pub lnav: bool,
 }
 
+impl TargetStateAndStatusInformation {
+    fn write_altitude<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        altitude: u32,
+    ) -> result::Result<(), DekuError> {
+        let altitude = if altitude == 0 { 0 } else { altitude / 32 + 1 };
+        altitude.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(12)))
+    }
+
+    fn write_qnh<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        qnh: f32,
+    ) -> result::Result<(), DekuError> {
+        let qnh = if qnh == 0.0 { 0 } else { libm::roundf((qnh - 800.0) / 0.8) as u32 + 1 };
+        qnh.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(9)))
+    }
+
+    fn write_heading<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        heading: f32,
+    ) -> result::Result<(), DekuError> {
+        let heading = libm::roundf(heading * 256.0 / 180.0) as u16;
+        heading.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(9)))
+    }
+}
+
 /// [`ME::AirborneVelocity`]
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirborneVelocity {
     #[deku(bits = "3")]
@@ -888,37 +1667,136 @@ pub struct AirborneVelocity {
     pub gnss_sign: Sign,
     #[deku(
         bits = "7",
-        map = "|gnss_baro_diff: u16| -> Result<_, DekuError> {Ok(if gnss_baro_diff > 1 {(gnss_baro_diff - 1)* 25} else { 0 })}"
+        map = "|gnss_baro_diff: u16| -> Result<_, DekuError> {Ok(if gnss_baro_diff > 1 {(gnss_baro_diff - 1)* 25} else { 0 })}",
+        writer = "(if *gnss_baro_diff > 0 { *gnss_baro_diff / 25 + 1 } else { 0 }).to_writer(deku::writer, deku::ctx::BitSize(7))"
     )]
     pub gnss_baro_diff: u16,
 }
 
 impl AirborneVelocity {
     /// Return effective (`heading`, `ground_speed`, `vertical_rate`) for groundspeed
+    ///
+    /// See [`Self::velocity`] for airspeed subtypes and unavailable vertical rates.
     #[must_use]
     pub fn calculate(&self) -> Option<(f32, f64, i16)> {
-        if let AirborneVelocitySubType::GroundSpeedDecoding(ground_speed) = &self.sub_type {
-            let v_ew = f64::from((ground_speed.ew_vel as i16 - 1) * ground_speed.ew_sign.value());
-            let v_ns = f64::from((ground_speed.ns_vel as i16 - 1) * ground_speed.ns_sign.value());
-            let h = libm::atan2(v_ew, v_ns) * (360.0 / (2.0 * f64::consts::PI));
-            let heading = if h < 0.0 { h + 360.0 } else { h };
-
-            let vrate = self
-                .vrate_value
-                .checked_sub(1)
-                .and_then(|v| v.checked_mul(64))
-                .map(|v| (v as i16) * self.vrate_sign.value());
-
-            if let Some(vrate) = vrate {
-                return Some((heading as f32, libm::hypot(v_ew, v_ns), vrate));
+        let velocity = self.velocity()?;
+        match velocity.horizontal? {
+            HorizontalVelocity::GroundSpeed { speed, track } => {
+                Some((track, speed, velocity.vertical_rate?))
             }
+            HorizontalVelocity::Airspeed { .. } => None,
         }
-        None
+    }
+
+    /// Decode every subtype but the reserved ones, `None` for those
+    #[must_use]
+    pub fn velocity(&self) -> Option<Velocity> {
+        let supersonic = matches!(self.st, 2 | 4);
+        let scale = if supersonic { 4 } else { 1 };
+        let horizontal = match &self.sub_type {
+            AirborneVelocitySubType::GroundSpeedDecoding(ground_speed) => {
+                if ground_speed.ew_vel == 0 || ground_speed.ns_vel == 0 {
+                    None
+                } else {
+                    let component = |vel: u16, sign: Sign| {
+                        f64::from((vel - 1) * scale) * f64::from(sign.value())
+                    };
+                    let v_ew = component(ground_speed.ew_vel, ground_speed.ew_sign);
+                    let v_ns = component(ground_speed.ns_vel, ground_speed.ns_sign);
+                    let h = libm::atan2(v_ew, v_ns) * (360.0 / (2.0 * f64::consts::PI));
+                    let track = if h < 0.0 { h + 360.0 } else { h };
+                    Some(HorizontalVelocity::GroundSpeed {
+                        speed: libm::hypot(v_ew, v_ns),
+                        track: track as f32,
+                    })
+                }
+            }
+            AirborneVelocitySubType::AirspeedDecoding(airspeed) => {
+                Some(HorizontalVelocity::Airspeed {
+                    speed: airspeed.airspeed.map(|speed| speed * scale),
+                    airspeed_type: if airspeed.airspeed_type == 0 {
+                        AirspeedType::Indicated
+                    } else {
+                        AirspeedType::True
+                    },
+                    heading: (airspeed.status_heading == 1)
+                        .then(|| f32::from(airspeed.mag_heading) * 360.0 / 1024.0),
+                })
+            }
+            AirborneVelocitySubType::Reserved0(_) | AirborneVelocitySubType::Reserved1(_) => {
+                return None
+            }
+        };
+
+        let vertical_rate =
+            self.vrate_value.checked_sub(1).map(|v| (v * 64) as i16 * self.vrate_sign.value());
+        Some(Velocity {
+            horizontal,
+            vertical_rate,
+            vrate_src: self.vrate_src,
+            gnss_baro_diff: self.gnss_baro_diff as i16 * self.gnss_sign.value(),
+            supersonic,
+        })
+    }
+}
+
+/// [`AirborneVelocity`] in knots, degrees and ft/min, from [`AirborneVelocity::velocity`]
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Velocity {
+    /// `None` if either component of the ground speed is unavailable
+    pub horizontal: Option<HorizontalVelocity>,
+    /// `None` if unavailable
+    pub vertical_rate: Option<i16>,
+    pub vrate_src: VerticalRateSource,
+    /// GNSS altitude minus barometric altitude, in ft
+    pub gnss_baro_diff: i16,
+    /// Subtypes 2 and 4, with speeds in units of 4 kt
+    pub supersonic: bool,
+}
+
+/// Speed and direction of [`Velocity`]
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HorizontalVelocity {
+    /// Subtypes 1 and 2
+    GroundSpeed {
+        speed: f64,
+        /// Clockwise from true north
+        track: f32,
+    },
+    /// Subtypes 3 and 4
+    Airspeed {
+        /// Knots, `None` if unavailable
+        speed: Option<u16>,
+        airspeed_type: AirspeedType,
+        /// Clockwise from magnetic north, `None` if unavailable
+        heading: Option<f32>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AirspeedType {
+    Indicated,
+    True,
+}
+
+impl fmt::Display for AirspeedType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Indicated => "IAS",
+                Self::True => "TAS",
+            }
+        )
     }
 }
 
 /// Airborne Velocity Message “Subtype” Code Field Encoding
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(ctx = "st: u8", id = "st")]
 pub enum AirborneVelocitySubType {
@@ -935,7 +1813,7 @@ pub enum AirborneVelocitySubType {
     Reserved1(#[deku(bits = "22")] u32),
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 pub enum AirborneVelocityType {
@@ -943,15 +1821,21 @@ pub enum AirborneVelocityType {
     Supersonic = 3,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[deku(ctx = "t: AirborneVelocityType")]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirborneVelocitySubFields {
     pub dew: DirectionEW,
-    #[deku(reader = "Self::read_v(deku::reader, t)")]
+    #[deku(
+        reader = "Self::read_v(deku::reader, t)",
+        writer = "Self::write_v(deku::writer, *vew, t)"
+    )]
     pub vew: u16,
     pub dns: DirectionNS,
-    #[deku(reader = "Self::read_v(deku::reader, t)")]
+    #[deku(
+        reader = "Self::read_v(deku::reader, t)",
+        writer = "Self::write_v(deku::writer, *vns, t)"
+    )]
     pub vns: u16,
 }
 
@@ -971,9 +1855,25 @@ impl AirborneVelocitySubFields {
             }
         }
     }
+
+    fn write_v<W: deku::no_std_io::Write + Seek>(
+        writer: &mut Writer<W>,
+        v: u16,
+        t: AirborneVelocityType,
+    ) -> result::Result<(), DekuError> {
+        let v = match t {
+            AirborneVelocityType::Subsonic => v,
+            AirborneVelocityType::Supersonic => v / 4,
+        };
+        let v = v
+            .checked_add(1)
+            .filter(|v| *v < 1 << 10)
+            .ok_or_else(|| DekuError::InvalidParam("velocity does not fit in 10 bits".into()))?;
+        v.to_writer(writer, (deku::ctx::Endian::Big, deku::ctx::BitSize(10)))
+    }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum DirectionEW {
@@ -981,7 +1881,7 @@ pub enum DirectionEW {
     EastToWest = 1,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum DirectionNS {
@@ -989,7 +1889,7 @@ pub enum DirectionNS {
     NorthToSouth = 1,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum SourceBitVerticalRate {
@@ -997,7 +1897,7 @@ pub enum SourceBitVerticalRate {
     Barometer = 1,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum SignBitVerticalRate {
@@ -1005,7 +1905,7 @@ pub enum SignBitVerticalRate {
     Down = 1,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, DekuRead, DekuWrite)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum SignBitGNSSBaroAltitudesDiff {
@@ -1013,7 +1913,7 @@ pub enum SignBitGNSSBaroAltitudesDiff {
     Below = 1,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum VerticalRateSource {
@@ -1034,10 +1934,10 @@ impl fmt::Display for VerticalRateSource {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SurfacePosition {
-    #[deku(bits = "2")]
+    #[deku(bits = "7")]
     pub mov: u8,
     pub s: StatusForGroundTrack,
     #[deku(bits = "7")]
@@ -1051,7 +1951,55 @@ pub struct SurfacePosition {
     pub lon_cpr: u32,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+impl SurfacePosition {
+    /// Ground speed in knots from the non-linear [`Self::mov`], `None` if not available
+    ///
+    /// reference: ICAO 9871 (A.2.3.3.1)
+    #[must_use]
+    pub fn ground_speed(&self) -> Option<f64> {
+        let (start, base, step) = match self.mov {
+            1 => return Some(0.0),
+            2..=8 => (2, 0.125, 0.125),
+            9..=12 => (9, 1.0, 0.25),
+            13..=38 => (13, 2.0, 0.5),
+            39..=93 => (39, 15.0, 1.0),
+            94..=108 => (94, 70.0, 2.0),
+            109..=123 => (109, 100.0, 5.0),
+            124 => return Some(175.0),
+            // 0: not available, 125..=127: reserved
+            _ => return None,
+        };
+        Some(base + f64::from(self.mov - start) * step)
+    }
+
+    /// Ground track in degrees clockwise from true north, `None` if [`Self::s`] is
+    /// [`StatusForGroundTrack::Invalid`]
+    #[must_use]
+    pub fn track(&self) -> Option<f32> {
+        match self.s {
+            StatusForGroundTrack::Valid => Some(f32::from(self.trk) * 360.0 / 128.0),
+            StatusForGroundTrack::Invalid => None,
+        }
+    }
+}
+
+impl fmt::Display for SurfacePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ground_speed) = self.ground_speed() {
+            writeln!(f, "  Groundspeed:   {ground_speed} kt")?;
+        }
+        if let Some(track) = self.track() {
+            writeln!(f, "  Track:         {track} deg")?;
+        }
+        writeln!(f, "  CPR type:      Surface")?;
+        writeln!(f, "  CPR odd flag:  {}", self.f)?;
+        writeln!(f, "  CPR latitude:  ({})", self.lat_cpr)?;
+        writeln!(f, "  CPR longitude: ({})", self.lon_cpr)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum StatusForGroundTrack {
@@ -1,37 +1,292 @@
 //! B-Definition Subfield for Comm-B Messages
 
-use alloc::format;
-use alloc::string::String;
 #[cfg(feature = "alloc")]
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 #[cfg(feature = "alloc")]
 use core::{
-    clone::Clone, cmp::PartialEq, fmt, fmt::Debug, prelude::rust_2021::derive, result::Result::Ok,
-    writeln,
+    clone::Clone,
+    cmp::{Ordering, PartialEq},
+    default::Default,
+    fmt,
+    fmt::Debug,
+    iter::{IntoIterator, Iterator},
+    marker::Copy,
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+    result,
+    result::Result::Ok,
+    write, writeln,
 };
 
+use deku::ctx::{BitSize, Endian};
+use deku::no_std_io::{Cursor, Read, Seek, Write};
 use deku::prelude::*;
 
-use crate::aircraft_identification_read;
+use crate::{aircraft_identification_read, aircraft_identification_write, Callsign};
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
-#[deku(id_type = "u8")]
+/// Comm-B MB field
+///
+/// [`BDS::from_mb`] only selects registers by the identifier in the first byte, (1,0) and (2,0).
+/// Registers without one, such as (4,0), (5,0) and (6,0), are read as [`BDS::Empty`] or
+/// [`BDS::Unknown`], use [`infer`] or [`infer_with_context`] to find the registers they match.
+#[derive(Debug, PartialEq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BDS {
     /// (1, 0) Table A-2-16
-    #[deku(id = "0x00")]
     Empty([u8; 6]),
 
     /// (1, 0) Table A-2-16
-    #[deku(id = "0x10")]
     DataLinkCapability(DataLinkCapability),
 
     /// (2, 0) Table A-2-32
-    #[deku(id = "0x20")]
-    AircraftIdentification(#[deku(reader = "aircraft_identification_read(deku::reader)")] String),
+    AircraftIdentification(Callsign),
 
-    #[deku(id_pat = "_")]
-    Unknown { id: u8, unknown: [u8; 6] },
+    /// (4, 0) Table A-2-64
+    SelectedVerticalIntention(SelectedVerticalIntention),
+
+    /// (4, 4) Table A-2-68
+    MeteorologicalRoutineAirReport(MeteorologicalRoutineAirReport),
+
+    /// (4, 5) Table A-2-69
+    MeteorologicalHazardReport(MeteorologicalHazardReport),
+
+    /// (5, 0) Table A-2-80
+    TrackAndTurnReport(TrackAndTurnReport),
+
+    /// (6, 0) Table A-2-96
+    HeadingAndSpeedReport(HeadingAndSpeedReport),
+
+    Unknown {
+        id: u8,
+        unknown: [u8; 6],
+    },
+}
+
+impl<'a> DekuReader<'a> for BDS {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> result::Result<Self, DekuError> {
+        let mb = <[u8; 7]>::from_reader_with_ctx(reader, ())?;
+        Self::from_mb(mb)
+    }
+}
+
+impl DekuWriter for BDS {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        _: (),
+    ) -> result::Result<(), DekuError> {
+        match self {
+            Self::Empty(unknown) => {
+                0x00_u8.to_writer(writer, ())?;
+                unknown.to_writer(writer, ())
+            }
+            Self::DataLinkCapability(capability) => {
+                0x10_u8.to_writer(writer, ())?;
+                capability.to_writer(writer, ())
+            }
+            Self::AircraftIdentification(cn) => {
+                0x20_u8.to_writer(writer, ())?;
+                aircraft_identification_write(writer, cn)
+            }
+            Self::SelectedVerticalIntention(intention) => intention.to_writer(writer, ()),
+            Self::MeteorologicalRoutineAirReport(report) => report.to_writer(writer, ()),
+            Self::MeteorologicalHazardReport(report) => report.to_writer(writer, ()),
+            Self::TrackAndTurnReport(report) => report.to_writer(writer, ()),
+            Self::HeadingAndSpeedReport(report) => report.to_writer(writer, ()),
+            Self::Unknown { id, unknown } => {
+                id.to_writer(writer, ())?;
+                unknown.to_writer(writer, ())
+            }
+        }
+    }
+}
+
+impl BDS {
+    /// Decode the 56 bit MB field of a Comm-B reply
+    ///
+    /// Only registers with an identifier, (1,0) and (2,0), are decoded. Everything else is
+    /// [`Self::Empty`] or [`Self::Unknown`], see [`infer`] for the registers it could be.
+    pub fn from_mb(mb: [u8; 7]) -> result::Result<Self, DekuError> {
+        let mut cursor = Cursor::new(&mb[1..]);
+        let mut reader = Reader::new(&mut cursor);
+        let unknown = [mb[1], mb[2], mb[3], mb[4], mb[5], mb[6]];
+        match mb[0] {
+            0x10 => Ok(Self::DataLinkCapability(DataLinkCapability::from_reader_with_ctx(
+                &mut reader,
+                (),
+            )?)),
+            0x20 => Ok(Self::AircraftIdentification(aircraft_identification_read(&mut reader)?)),
+            0x00 => Ok(Self::Empty(unknown)),
+            id => Ok(Self::Unknown { id, unknown }),
+        }
+    }
+
+    /// The only register an [`Self::Empty`] or [`Self::Unknown`] MB field can be inferred as,
+    /// `None` if no register or more than one matches
+    pub(crate) fn unambiguous(&self) -> Option<Self> {
+        let (id, unknown) = match self {
+            Self::Empty(unknown) => (0x00, unknown),
+            Self::Unknown { id, unknown } => (*id, unknown),
+            _ => return None,
+        };
+        let mut mb = [id; 7];
+        mb[1..].copy_from_slice(unknown);
+        let mut inferred = Self::inferred(&mb);
+        let bds = inferred.next()?;
+        inferred.next().is_none().then_some(bds)
+    }
+
+    /// Registers without an identifier whose checks pass for `mb`, in the order they are tried
+    fn inferred(mb: &[u8; 7]) -> impl Iterator<Item = Self> + '_ {
+        let decoders: [Decoder; 5] = [
+            |mb| {
+                decode_valid(mb, SelectedVerticalIntention::is_valid)
+                    .map(Self::SelectedVerticalIntention)
+            },
+            |mb| decode_valid(mb, TrackAndTurnReport::is_valid).map(Self::TrackAndTurnReport),
+            |mb| decode_valid(mb, HeadingAndSpeedReport::is_valid).map(Self::HeadingAndSpeedReport),
+            |mb| {
+                decode_valid(mb, MeteorologicalRoutineAirReport::is_valid)
+                    .map(Self::MeteorologicalRoutineAirReport)
+            },
+            |mb| {
+                decode_valid(mb, MeteorologicalHazardReport::is_valid)
+                    .map(Self::MeteorologicalHazardReport)
+            },
+        ];
+        decoders.into_iter().filter_map(move |decode| decode(mb))
+    }
+}
+
+/// Decode an MB field as a register without an identifier, `None` if it doesn't match
+type Decoder = fn(&[u8; 7]) -> Option<BDS>;
+
+/// Decode `mb` as `T` if it isn't empty and `is_valid` passes
+fn decode_valid<T>(mb: &[u8; 7], is_valid: fn(&[u8; 7]) -> bool) -> Option<T>
+where
+    T: for<'a> DekuContainerRead<'a>,
+{
+    if *mb == [0; 7] || !is_valid(mb) {
+        return None;
+    }
+    T::from_bytes((mb, 0)).ok().map(|(_, register)| register)
+}
+
+/// Furthest a (5,0) ground speed can be from [`Context::ground_speed`] (kt)
+const MAX_SPEED_DIFFERENCE: f32 = 50.0;
+
+/// Furthest a (5,0) track or (6,0) magnetic heading can be from [`Context::heading`] (degrees)
+const MAX_ANGLE_DIFFERENCE: f32 = 45.0;
+
+/// Possible decoding of a Comm-B MB field, returned from [`infer`]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate {
+    pub bds: BDS,
+    /// Sum of the differences between the decoded values and the [`Context`], in kt and degrees.
+    /// `None` when nothing in the register could be compared
+    pub distance: Option<f32>,
+}
+
+/// Aircraft state known from other messages, such as ADS-B airborne velocity, used to narrow
+/// down the [`Candidate`]s of [`infer_with_context`]
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Context {
+    /// Ground speed (kt)
+    pub ground_speed: Option<f32>,
+    /// Track over ground (degrees)
+    pub heading: Option<f32>,
+}
+
+impl Context {
+    /// Distance of `bds` from this context, `Err` if `bds` contradicts it
+    fn distance(&self, bds: &BDS) -> result::Result<Option<f32>, ()> {
+        let mut distance = None;
+        let mut compare = |a: Option<f32>, b: Option<f32>, max: f32, diff: fn(f32, f32) -> f32| {
+            if let (Some(a), Some(b)) = (a, b) {
+                let diff = diff(a, b);
+                if diff > max {
+                    return Err(());
+                }
+                *distance.get_or_insert(0.0) += diff;
+            }
+            Ok(())
+        };
+        match bds {
+            BDS::TrackAndTurnReport(report) => {
+                let ground_speed = report.ground_speed.map(f32::from);
+                compare(ground_speed, self.ground_speed, MAX_SPEED_DIFFERENCE, |a, b| {
+                    libm::fabsf(a - b)
+                })?;
+                compare(report.track_angle, self.heading, MAX_ANGLE_DIFFERENCE, angle_difference)?;
+            }
+            BDS::HeadingAndSpeedReport(report) => {
+                compare(
+                    report.magnetic_heading,
+                    self.heading,
+                    MAX_ANGLE_DIFFERENCE,
+                    angle_difference,
+                )?;
+            }
+            _ => (),
+        }
+        Ok(distance)
+    }
+}
+
+/// Smallest difference between two angles (degrees)
+fn angle_difference(a: f32, b: f32) -> f32 {
+    let diff = libm::fabsf(a - b) % 360.0;
+    if diff > 180.0 {
+        360.0 - diff
+    } else {
+        diff
+    }
+}
+
+/// Every register that `mb` could be, decoded
+///
+/// Registers with an identifier, (1,0) and (2,0), are matched on that identifier. The rest are
+/// matched with status bit, reserved bit and value range checks, so more than one can match.
+/// An empty MB field only returns [`BDS::Empty`]. Nothing is returned when no register matches.
+///
+/// See [`infer_with_context`] to rank the candidates with the aircraft's known state.
+#[must_use]
+pub fn infer(mb: [u8; 7]) -> Vec<Candidate> {
+    infer_with_context(mb, &Context::default())
+}
+
+/// [`infer`], dropping candidates that contradict `context`
+///
+/// Remaining candidates are ranked by [`Candidate::distance`], closest first, followed by the
+/// candidates with nothing to compare.
+#[must_use]
+pub fn infer_with_context(mb: [u8; 7], context: &Context) -> Vec<Candidate> {
+    if mb == [0; 7] {
+        return vec![Candidate { bds: BDS::Empty([0; 6]), distance: None }];
+    }
+    let identified = match mb[0] {
+        0x10 | 0x20 => BDS::from_mb(mb).ok(),
+        _ => None,
+    };
+    let mut candidates: Vec<Candidate> = identified
+        .into_iter()
+        .chain(BDS::inferred(&mb))
+        .filter_map(|bds| {
+            let distance = context.distance(&bds).ok()?;
+            Some(Candidate { bds, distance })
+        })
+        .collect();
+    candidates.sort_by(|a, b| match (a.distance, b.distance) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+    candidates
 }
 
 impl fmt::Display for BDS {
@@ -47,6 +302,26 @@ impl fmt::Display for BDS {
             Self::DataLinkCapability(_) => {
                 writeln!(f, "Comm-B format: BDS1,0 Datalink capabilities")?;
             }
+            Self::SelectedVerticalIntention(intention) => {
+                writeln!(f, "Comm-B format: BDS4,0 Selected vertical intention")?;
+                write!(f, "{intention}")?;
+            }
+            Self::MeteorologicalRoutineAirReport(report) => {
+                writeln!(f, "Comm-B format: BDS4,4 Meteorological routine air report")?;
+                write!(f, "{report}")?;
+            }
+            Self::MeteorologicalHazardReport(report) => {
+                writeln!(f, "Comm-B format: BDS4,5 Meteorological hazard report")?;
+                write!(f, "{report}")?;
+            }
+            Self::TrackAndTurnReport(report) => {
+                writeln!(f, "Comm-B format: BDS5,0 Track and turn report")?;
+                write!(f, "{report}")?;
+            }
+            Self::HeadingAndSpeedReport(report) => {
+                writeln!(f, "Comm-B format: BDS6,0 Heading and speed report")?;
+                write!(f, "{report}")?;
+            }
             Self::Unknown { .. } => {
                 writeln!(f, "Comm-B format: unknown format")?;
             }
@@ -56,7 +331,7 @@ impl fmt::Display for BDS {
 }
 
 /// To report the data link capability of the Mode S transponder/data link installation
-#[derive(Debug, PartialEq, Eq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataLinkCapability {
     #[deku(bits = "1")]
@@ -88,3 +363,787 @@ pub struct DataLinkCapability {
     pub reserved_acas: u8,
     pub bit_array: u16,
 }
+
+/// To report the aircraft's current vertical intentions
+///
+/// Fields are `None` when their status bit is not set.
+///
+/// reference: ICAO 9871 (Table A-2-64)
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectedVerticalIntention {
+    /// MCP/FCU selected altitude (ft)
+    #[deku(
+        reader = "Self::read_altitude(deku::reader)",
+        writer = "Self::write_altitude(deku::writer, *mcp_altitude)"
+    )]
+    pub mcp_altitude: Option<u16>,
+    /// FMS selected altitude (ft)
+    #[deku(
+        reader = "Self::read_altitude(deku::reader)",
+        writer = "Self::write_altitude(deku::writer, *fms_altitude)"
+    )]
+    pub fms_altitude: Option<u16>,
+    /// Barometric pressure setting (millibars), sent as 0.1 mb above 800 mb
+    #[deku(
+        reader = "Self::read_barometric_pressure(deku::reader)",
+        writer = "Self::write_barometric_pressure(deku::writer, *barometric_pressure)",
+        pad_bits_after = "8" // reserved
+    )]
+    pub barometric_pressure: Option<f32>,
+    #[deku(bits = "1")]
+    pub mode_status: bool,
+    #[deku(bits = "1")]
+    pub vnav: bool,
+    #[deku(bits = "1")]
+    pub alt_hold: bool,
+    #[deku(bits = "1")]
+    #[deku(pad_bits_after = "2")] // reserved
+    pub approach: bool,
+    #[deku(bits = "1")]
+    pub target_altitude_source_status: bool,
+    pub target_altitude_source: TargetAltitudeSource,
+}
+
+impl SelectedVerticalIntention {
+    /// Check that the status and reserved bits of `mb` are consistent with BDS (4,0)
+    #[must_use]
+    pub fn is_valid(mb: &[u8; 7]) -> bool {
+        !(wrong_status(mb, 1, 2, 13)
+            || wrong_status(mb, 14, 15, 26)
+            || wrong_status(mb, 27, 28, 39)
+            || wrong_status(mb, 48, 49, 51)
+            || wrong_status(mb, 54, 55, 56)
+            || mb_bits(mb, 40, 47) != 0
+            || mb_bits(mb, 52, 53) != 0)
+    }
+
+    fn read_altitude<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<u16>, DekuError> {
+        Ok(read_status(reader, 12)?.map(|altitude| altitude * 16))
+    }
+
+    fn write_altitude<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        altitude: Option<u16>,
+    ) -> result::Result<(), DekuError> {
+        write_status(writer, altitude.map(|altitude| altitude / 16), 12)
+    }
+
+    fn read_barometric_pressure<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_status(reader, 12)?.map(|pressure| 800.0 + f32::from(pressure) * 0.1))
+    }
+
+    fn write_barometric_pressure<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        pressure: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let pressure = pressure.map(|pressure| libm::roundf((pressure - 800.0) * 10.0) as u16);
+        write_status(writer, pressure, 12)
+    }
+}
+
+impl fmt::Display for SelectedVerticalIntention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(altitude) = self.mcp_altitude {
+            writeln!(f, "  MCP altitude:  {altitude} ft")?;
+        }
+        if let Some(altitude) = self.fms_altitude {
+            writeln!(f, "  FMS altitude:  {altitude} ft")?;
+        }
+        if let Some(pressure) = self.barometric_pressure {
+            writeln!(f, "  Baro setting:  {pressure:.1} millibars")?;
+        }
+        if self.mode_status {
+            write!(f, "  Modes:        ")?;
+            if self.vnav {
+                write!(f, " vnav")?;
+            }
+            if self.alt_hold {
+                write!(f, " altitude-hold")?;
+            }
+            if self.approach {
+                write!(f, " approach")?;
+            }
+            writeln!(f)?;
+        }
+        if self.target_altitude_source_status {
+            writeln!(f, "  Target source: {}", self.target_altitude_source)?;
+        }
+        Ok(())
+    }
+}
+
+/// Source of the target altitude in [`SelectedVerticalIntention`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[deku(id_type = "u8", bits = "2")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TargetAltitudeSource {
+    Unknown = 0,
+    AircraftAltitude = 1,
+    McpFcuSelectedAltitude = 2,
+    FmsSelectedAltitude = 3,
+}
+
+impl fmt::Display for TargetAltitudeSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Unknown => "unknown",
+                Self::AircraftAltitude => "aircraft altitude",
+                Self::McpFcuSelectedAltitude => "MCP/FCU selected altitude",
+                Self::FmsSelectedAltitude => "FMS selected altitude",
+            }
+        )
+    }
+}
+
+/// To report meteorological data measured by the aircraft
+///
+/// Fields are `None` when their status bit is not set.
+///
+/// reference: ICAO 9871 (Table A-2-68)
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeteorologicalRoutineAirReport {
+    /// Figure of merit/source: 0 invalid, 1 INS, 2 GNSS, 3 DME/DME, 4 VOR/DME, 5..=15 reserved
+    #[deku(bits = "4")]
+    pub figure_of_merit: u8,
+    /// Wind speed (kt) and direction (degrees)
+    #[deku(
+        reader = "Self::read_wind(deku::reader)",
+        writer = "Self::write_wind(deku::writer, *wind)"
+    )]
+    pub wind: Option<(u16, f32)>,
+    /// Static air temperature (C)
+    #[deku(
+        reader = "Self::read_temperature(deku::reader)",
+        writer = "Self::write_temperature(deku::writer, *static_air_temperature)"
+    )]
+    pub static_air_temperature: f32,
+    /// Average static pressure (hPa)
+    #[deku(
+        reader = "read_status(deku::reader, 11)",
+        writer = "write_status(deku::writer, *static_pressure, 11)"
+    )]
+    pub static_pressure: Option<u16>,
+    #[deku(
+        reader = "read_hazard(deku::reader)",
+        writer = "write_hazard(deku::writer, *turbulence)"
+    )]
+    pub turbulence: Option<HazardLevel>,
+    /// Humidity (%)
+    #[deku(
+        reader = "Self::read_humidity(deku::reader)",
+        writer = "Self::write_humidity(deku::writer, *humidity)"
+    )]
+    pub humidity: Option<f32>,
+}
+
+impl MeteorologicalRoutineAirReport {
+    /// Check that the status bits of `mb` are consistent with BDS (4,4), and that the source, wind
+    /// speed and temperature are within what an aircraft reports
+    #[must_use]
+    pub fn is_valid(mb: &[u8; 7]) -> bool {
+        if wrong_status(mb, 5, 6, 23)
+            || wrong_status(mb, 35, 36, 46)
+            || wrong_status(mb, 47, 48, 49)
+            || wrong_status(mb, 50, 51, 56)
+            || mb_bits(mb, 1, 4) > 4
+        {
+            return false;
+        }
+        let Ok((_, report)) = Self::from_bytes((mb, 0)) else {
+            return false;
+        };
+        if report.wind.is_some_and(|(speed, _)| speed > 250) {
+            return false;
+        }
+        (-80.0..=60.0).contains(&report.static_air_temperature)
+    }
+
+    fn read_wind<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<(u16, f32)>, DekuError> {
+        let speed = read_status(reader, 9)?;
+        let direction = u16::from_reader_with_ctx(reader, (Endian::Big, BitSize(9)))?;
+        Ok(speed.map(|speed| (speed, f32::from(direction) * 180.0 / 256.0)))
+    }
+
+    fn write_wind<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        wind: Option<(u16, f32)>,
+    ) -> result::Result<(), DekuError> {
+        let (speed, direction) = wind.unzip();
+        write_status(writer, speed, 9)?;
+        let direction =
+            direction.map_or(0, |direction| libm::roundf(direction * 256.0 / 180.0) as u16);
+        direction.to_writer(writer, (Endian::Big, BitSize(9)))
+    }
+
+    fn read_temperature<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<f32, DekuError> {
+        let temperature = u16::from_reader_with_ctx(reader, (Endian::Big, BitSize(11)))?;
+        Ok(f32::from(sign_extend(temperature, 11)) * 0.25)
+    }
+
+    fn write_temperature<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        temperature: f32,
+    ) -> result::Result<(), DekuError> {
+        let temperature = libm::roundf(temperature * 4.0) as i16 as u16 & 0x7ff;
+        temperature.to_writer(writer, (Endian::Big, BitSize(11)))
+    }
+
+    fn read_humidity<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_status(reader, 6)?.map(|humidity| f32::from(humidity) * 100.0 / 64.0))
+    }
+
+    fn write_humidity<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        humidity: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let humidity = humidity.map(|humidity| libm::roundf(humidity * 64.0 / 100.0) as u16);
+        write_status(writer, humidity, 6)
+    }
+}
+
+impl fmt::Display for MeteorologicalRoutineAirReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match self.figure_of_merit {
+            0 => "invalid",
+            1 => "INS",
+            2 => "GNSS",
+            3 => "DME/DME",
+            4 => "VOR/DME",
+            _ => "reserved",
+        };
+        writeln!(f, "  Source:        {source}")?;
+        if let Some((speed, direction)) = self.wind {
+            writeln!(f, "  Wind:          {speed} kt from {direction:.1} deg")?;
+        }
+        writeln!(f, "  Temperature:   {:.2} C", self.static_air_temperature)?;
+        if let Some(pressure) = self.static_pressure {
+            writeln!(f, "  Pressure:      {pressure} hPa")?;
+        }
+        if let Some(turbulence) = self.turbulence {
+            writeln!(f, "  Turbulence:    {turbulence}")?;
+        }
+        if let Some(humidity) = self.humidity {
+            writeln!(f, "  Humidity:      {humidity:.1} %")?;
+        }
+        Ok(())
+    }
+}
+
+/// To report meteorological hazards encountered by the aircraft
+///
+/// Fields are `None` when their status bit is not set.
+///
+/// reference: ICAO 9871 (Table A-2-69)
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeteorologicalHazardReport {
+    #[deku(
+        reader = "read_hazard(deku::reader)",
+        writer = "write_hazard(deku::writer, *turbulence)"
+    )]
+    pub turbulence: Option<HazardLevel>,
+    #[deku(
+        reader = "read_hazard(deku::reader)",
+        writer = "write_hazard(deku::writer, *wind_shear)"
+    )]
+    pub wind_shear: Option<HazardLevel>,
+    #[deku(
+        reader = "read_hazard(deku::reader)",
+        writer = "write_hazard(deku::writer, *microburst)"
+    )]
+    pub microburst: Option<HazardLevel>,
+    #[deku(reader = "read_hazard(deku::reader)", writer = "write_hazard(deku::writer, *icing)")]
+    pub icing: Option<HazardLevel>,
+    #[deku(
+        reader = "read_hazard(deku::reader)",
+        writer = "write_hazard(deku::writer, *wake_vortex)"
+    )]
+    pub wake_vortex: Option<HazardLevel>,
+    /// Static air temperature (C)
+    #[deku(
+        reader = "Self::read_temperature(deku::reader)",
+        writer = "Self::write_temperature(deku::writer, *static_air_temperature)"
+    )]
+    pub static_air_temperature: Option<f32>,
+    /// Average static pressure (hPa)
+    #[deku(
+        reader = "read_status(deku::reader, 11)",
+        writer = "write_status(deku::writer, *static_pressure, 11)"
+    )]
+    pub static_pressure: Option<u16>,
+    /// Radio height (ft)
+    #[deku(
+        reader = "Self::read_radio_height(deku::reader)",
+        writer = "Self::write_radio_height(deku::writer, *radio_height)",
+        pad_bits_after = "5" // reserved
+    )]
+    pub radio_height: Option<u16>,
+}
+
+impl MeteorologicalHazardReport {
+    /// Check that the status and reserved bits of `mb` are consistent with BDS (4,5), and that the
+    /// temperature is within what an aircraft reports
+    #[must_use]
+    pub fn is_valid(mb: &[u8; 7]) -> bool {
+        if wrong_status(mb, 1, 2, 3)
+            || wrong_status(mb, 4, 5, 6)
+            || wrong_status(mb, 7, 8, 9)
+            || wrong_status(mb, 10, 11, 12)
+            || wrong_status(mb, 13, 14, 15)
+            || wrong_status(mb, 16, 17, 26)
+            || wrong_status(mb, 27, 28, 38)
+            || wrong_status(mb, 39, 40, 51)
+            || mb_bits(mb, 52, 56) != 0
+        {
+            return false;
+        }
+        let Ok((_, report)) = Self::from_bytes((mb, 0)) else {
+            return false;
+        };
+        report.static_air_temperature.is_none_or(|sat| (-80.0..=60.0).contains(&sat))
+    }
+
+    fn read_temperature<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_signed_status(reader, 10)?.map(|temperature| f32::from(temperature) * 0.25))
+    }
+
+    fn write_temperature<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        temperature: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let temperature = temperature.map(|temperature| libm::roundf(temperature * 4.0) as i16);
+        write_signed_status(writer, temperature, 10)
+    }
+
+    fn read_radio_height<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<u16>, DekuError> {
+        Ok(read_status(reader, 12)?.map(|height| height * 16))
+    }
+
+    fn write_radio_height<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        height: Option<u16>,
+    ) -> result::Result<(), DekuError> {
+        write_status(writer, height.map(|height| height / 16), 12)
+    }
+}
+
+impl fmt::Display for MeteorologicalHazardReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(turbulence) = self.turbulence {
+            writeln!(f, "  Turbulence:    {turbulence}")?;
+        }
+        if let Some(wind_shear) = self.wind_shear {
+            writeln!(f, "  Wind shear:    {wind_shear}")?;
+        }
+        if let Some(microburst) = self.microburst {
+            writeln!(f, "  Microburst:    {microburst}")?;
+        }
+        if let Some(icing) = self.icing {
+            writeln!(f, "  Icing:         {icing}")?;
+        }
+        if let Some(wake_vortex) = self.wake_vortex {
+            writeln!(f, "  Wake vortex:   {wake_vortex}")?;
+        }
+        if let Some(temperature) = self.static_air_temperature {
+            writeln!(f, "  Temperature:   {temperature:.2} C")?;
+        }
+        if let Some(pressure) = self.static_pressure {
+            writeln!(f, "  Pressure:      {pressure} hPa")?;
+        }
+        if let Some(height) = self.radio_height {
+            writeln!(f, "  Radio height:  {height} ft")?;
+        }
+        Ok(())
+    }
+}
+
+/// Level of a hazard in [`MeteorologicalRoutineAirReport`] and [`MeteorologicalHazardReport`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HazardLevel {
+    Nil = 0,
+    Light = 1,
+    Moderate = 2,
+    Severe = 3,
+}
+
+impl fmt::Display for HazardLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Nil => "nil",
+                Self::Light => "light",
+                Self::Moderate => "moderate",
+                Self::Severe => "severe",
+            }
+        )
+    }
+}
+
+/// Read a status bit followed by a 2 bit [`HazardLevel`], `None` if the status bit is not set
+fn read_hazard<R: Read + Seek>(
+    reader: &mut Reader<R>,
+) -> result::Result<Option<HazardLevel>, DekuError> {
+    Ok(read_status(reader, 2)?.map(|level| match level {
+        0 => HazardLevel::Nil,
+        1 => HazardLevel::Light,
+        2 => HazardLevel::Moderate,
+        _ => HazardLevel::Severe,
+    }))
+}
+
+fn write_hazard<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    level: Option<HazardLevel>,
+) -> result::Result<(), DekuError> {
+    write_status(writer, level.map(|level| level as u16), 2)
+}
+
+/// To report the track and turn state of the aircraft
+///
+/// Fields are `None` when their status bit is not set.
+///
+/// reference: ICAO 9871 (Table A-2-80)
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackAndTurnReport {
+    /// Roll angle, positive is right wing down (degrees)
+    #[deku(
+        reader = "Self::read_roll_angle(deku::reader)",
+        writer = "Self::write_roll_angle(deku::writer, *roll_angle)"
+    )]
+    pub roll_angle: Option<f32>,
+    /// True track angle, 0..360 (degrees)
+    #[deku(
+        reader = "read_angle(deku::reader)",
+        writer = "write_angle(deku::writer, *track_angle)"
+    )]
+    pub track_angle: Option<f32>,
+    /// Ground speed (kt)
+    #[deku(
+        reader = "read_speed(deku::reader)",
+        writer = "write_speed(deku::writer, *ground_speed)"
+    )]
+    pub ground_speed: Option<u16>,
+    /// Track angle rate, positive is clockwise (degrees/s)
+    #[deku(
+        reader = "Self::read_track_angle_rate(deku::reader)",
+        writer = "Self::write_track_angle_rate(deku::writer, *track_angle_rate)"
+    )]
+    pub track_angle_rate: Option<f32>,
+    /// True airspeed (kt)
+    #[deku(
+        reader = "read_speed(deku::reader)",
+        writer = "write_speed(deku::writer, *true_airspeed)"
+    )]
+    pub true_airspeed: Option<u16>,
+}
+
+impl TrackAndTurnReport {
+    /// Check that the status bits of `mb` are consistent with BDS (5,0), and that the roll angle
+    /// and speeds are within what an aircraft reports
+    #[must_use]
+    pub fn is_valid(mb: &[u8; 7]) -> bool {
+        if wrong_status(mb, 1, 2, 11)
+            || wrong_status(mb, 12, 13, 23)
+            || wrong_status(mb, 24, 25, 34)
+            || wrong_status(mb, 35, 36, 45)
+            || wrong_status(mb, 46, 47, 56)
+        {
+            return false;
+        }
+        let Ok((_, report)) = Self::from_bytes((mb, 0)) else {
+            return false;
+        };
+        if report.roll_angle.is_some_and(|roll| libm::fabsf(roll) > 50.0) {
+            return false;
+        }
+        if report.ground_speed.is_some_and(|gs| gs > 600)
+            || report.true_airspeed.is_some_and(|tas| tas > 600)
+        {
+            return false;
+        }
+        // wind doesn't account for more than this
+        if let (Some(gs), Some(tas)) = (report.ground_speed, report.true_airspeed) {
+            if gs.abs_diff(tas) > 200 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn read_roll_angle<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_signed_status(reader, 10)?.map(|roll| f32::from(roll) * 45.0 / 256.0))
+    }
+
+    fn write_roll_angle<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        roll: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let roll = roll.map(|roll| libm::roundf(roll * 256.0 / 45.0) as i16);
+        write_signed_status(writer, roll, 10)
+    }
+
+    fn read_track_angle_rate<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_signed_status(reader, 10)?.map(|rate| f32::from(rate) * 8.0 / 256.0))
+    }
+
+    fn write_track_angle_rate<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        rate: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let rate = rate.map(|rate| libm::roundf(rate * 256.0 / 8.0) as i16);
+        write_signed_status(writer, rate, 10)
+    }
+}
+
+impl fmt::Display for TrackAndTurnReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(roll) = self.roll_angle {
+            writeln!(f, "  Roll angle:    {roll:.1} deg")?;
+        }
+        if let Some(track) = self.track_angle {
+            writeln!(f, "  Track angle:   {track:.1} deg")?;
+        }
+        if let Some(gs) = self.ground_speed {
+            writeln!(f, "  Speed:         {gs} kt groundspeed")?;
+        }
+        if let Some(rate) = self.track_angle_rate {
+            writeln!(f, "  Track rate:    {rate:.3} deg/s")?;
+        }
+        if let Some(tas) = self.true_airspeed {
+            writeln!(f, "  TAS:           {tas} kt")?;
+        }
+        Ok(())
+    }
+}
+
+/// To report the heading and speed of the aircraft
+///
+/// Fields are `None` when their status bit is not set.
+///
+/// reference: ICAO 9871 (Table A-2-96)
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeadingAndSpeedReport {
+    /// Magnetic heading, 0..360 (degrees)
+    #[deku(
+        reader = "read_angle(deku::reader)",
+        writer = "write_angle(deku::writer, *magnetic_heading)"
+    )]
+    pub magnetic_heading: Option<f32>,
+    /// Indicated airspeed (kt)
+    #[deku(
+        reader = "read_status(deku::reader, 10)",
+        writer = "write_status(deku::writer, *indicated_airspeed, 10)"
+    )]
+    pub indicated_airspeed: Option<u16>,
+    /// Mach number
+    #[deku(
+        reader = "Self::read_mach(deku::reader)",
+        writer = "Self::write_mach(deku::writer, *mach)"
+    )]
+    pub mach: Option<f32>,
+    /// Barometric altitude rate (ft/min)
+    #[deku(
+        reader = "Self::read_vertical_rate(deku::reader)",
+        writer = "Self::write_vertical_rate(deku::writer, *barometric_altitude_rate)"
+    )]
+    pub barometric_altitude_rate: Option<i16>,
+    /// Inertial vertical velocity (ft/min)
+    #[deku(
+        reader = "Self::read_vertical_rate(deku::reader)",
+        writer = "Self::write_vertical_rate(deku::writer, *inertial_vertical_velocity)"
+    )]
+    pub inertial_vertical_velocity: Option<i16>,
+}
+
+impl HeadingAndSpeedReport {
+    /// Check that the status bits of `mb` are consistent with BDS (6,0), and that the speeds and
+    /// vertical rates are within what an aircraft reports
+    #[must_use]
+    pub fn is_valid(mb: &[u8; 7]) -> bool {
+        if wrong_status(mb, 1, 2, 12)
+            || wrong_status(mb, 13, 14, 23)
+            || wrong_status(mb, 24, 25, 34)
+            || wrong_status(mb, 35, 36, 45)
+            || wrong_status(mb, 46, 47, 56)
+        {
+            return false;
+        }
+        let Ok((_, report)) = Self::from_bytes((mb, 0)) else {
+            return false;
+        };
+        if report.indicated_airspeed.is_some_and(|ias| ias > 500)
+            || report.mach.is_some_and(|mach| mach > 1.0)
+        {
+            return false;
+        }
+        if report.barometric_altitude_rate.is_some_and(|rate| rate.unsigned_abs() > 6000)
+            || report.inertial_vertical_velocity.is_some_and(|rate| rate.unsigned_abs() > 6000)
+        {
+            return false;
+        }
+        true
+    }
+
+    fn read_mach<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<Option<f32>, DekuError> {
+        Ok(read_status(reader, 10)?.map(|mach| f32::from(mach) * 2.048 / 512.0))
+    }
+
+    fn write_mach<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        mach: Option<f32>,
+    ) -> result::Result<(), DekuError> {
+        let mach = mach.map(|mach| libm::roundf(mach * 512.0 / 2.048) as u16);
+        write_status(writer, mach, 10)
+    }
+
+    fn read_vertical_rate<R: Read + Seek>(
+        reader: &mut Reader<R>,
+    ) -> result::Result<Option<i16>, DekuError> {
+        Ok(read_signed_status(reader, 10)?.map(|rate| rate * 32))
+    }
+
+    fn write_vertical_rate<W: Write + Seek>(
+        writer: &mut Writer<W>,
+        rate: Option<i16>,
+    ) -> result::Result<(), DekuError> {
+        write_signed_status(writer, rate.map(|rate| rate / 32), 10)
+    }
+}
+
+impl fmt::Display for HeadingAndSpeedReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(heading) = self.magnetic_heading {
+            writeln!(f, "  Heading:       {heading:.1} deg magnetic")?;
+        }
+        if let Some(ias) = self.indicated_airspeed {
+            writeln!(f, "  IAS:           {ias} kt")?;
+        }
+        if let Some(mach) = self.mach {
+            writeln!(f, "  Mach:          {mach:.3}")?;
+        }
+        if let Some(rate) = self.barometric_altitude_rate {
+            writeln!(f, "  Baro rate:     {rate} ft/min")?;
+        }
+        if let Some(rate) = self.inertial_vertical_velocity {
+            writeln!(f, "  Inertial rate: {rate} ft/min")?;
+        }
+        Ok(())
+    }
+}
+
+/// Bits `start..=end` of `mb`, numbered from 1 as in the ICAO 9871 register tables
+fn mb_bits(mb: &[u8; 7], start: u32, end: u32) -> u64 {
+    let mb = u64::from_be_bytes([0, mb[0], mb[1], mb[2], mb[3], mb[4], mb[5], mb[6]]);
+    (mb >> (56 - end)) & ((1 << (end - start + 1)) - 1)
+}
+
+/// Status bit is not set, but the field it covers is not zero
+fn wrong_status(mb: &[u8; 7], status: u32, start: u32, end: u32) -> bool {
+    mb_bits(mb, status, status) == 0 && mb_bits(mb, start, end) != 0
+}
+
+/// Read a status bit followed by a `bits` wide value, `None` if the status bit is not set
+fn read_status<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    bits: usize,
+) -> result::Result<Option<u16>, DekuError> {
+    let status = bool::from_reader_with_ctx(reader, BitSize(1))?;
+    let value = u16::from_reader_with_ctx(reader, (Endian::Big, BitSize(bits)))?;
+    Ok(if status { Some(value) } else { None })
+}
+
+fn write_status<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    value: Option<u16>,
+    bits: usize,
+) -> result::Result<(), DekuError> {
+    value.is_some().to_writer(writer, BitSize(1))?;
+    value.unwrap_or(0).to_writer(writer, (Endian::Big, BitSize(bits)))
+}
+
+/// Read a status bit followed by a `bits` wide two's complement value (sign bit included), `None`
+/// if the status bit is not set
+fn read_signed_status<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    bits: usize,
+) -> result::Result<Option<i16>, DekuError> {
+    Ok(read_status(reader, bits)?.map(|value| sign_extend(value, bits)))
+}
+
+/// Value of a `bits` wide two's complement number
+fn sign_extend(value: u16, bits: usize) -> i16 {
+    if value & (1 << (bits - 1)) == 0 {
+        value as i16
+    } else {
+        value as i16 - (1 << bits)
+    }
+}
+
+fn write_signed_status<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    value: Option<i16>,
+    bits: usize,
+) -> result::Result<(), DekuError> {
+    write_status(writer, value.map(|value| value as u16 & ((1 << bits) - 1)), bits)
+}
+
+/// Track or heading angle, 11 bit two's complement in units of 90/512 degrees
+fn read_angle<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<Option<f32>, DekuError> {
+    Ok(read_signed_status(reader, 11)?.map(|angle| {
+        let angle = f32::from(angle) * 90.0 / 512.0;
+        if angle < 0.0 {
+            angle + 360.0
+        } else {
+            angle
+        }
+    }))
+}
+
+fn write_angle<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    angle: Option<f32>,
+) -> result::Result<(), DekuError> {
+    let angle = angle.map(|angle| {
+        let angle = if angle >= 180.0 { angle - 360.0 } else { angle };
+        libm::roundf(angle * 512.0 / 90.0) as i16
+    });
+    write_signed_status(writer, angle, 11)
+}
+
+/// Ground or true airspeed, 10 bits in units of 2 kt
+fn read_speed<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<Option<u16>, DekuError> {
+    Ok(read_status(reader, 10)?.map(|speed| speed * 2))
+}
+
+fn write_speed<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    speed: Option<u16>,
+) -> result::Result<(), DekuError> {
+    write_status(writer, speed.map(|speed| speed / 2), 10)
+}
@@ -0,0 +1,254 @@
+//! Comm-D Extended Length Message (ELM) segments and their reassembly
+//!
+//! A downlink ELM is announced with a [`crate::DownlinkRequest`] of 16..=31 in a surveillance or
+//! Comm-B reply, and then sent as up to 16 [`DF::CommDExtendedLengthMessage`] segments of 80 bits
+//! each.
+//!
+//! reference: ICAO Annex 10 Vol IV 3.1.2.7.3 and 3.1.2.7.7
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, fmt, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    fmt::Debug,
+    iter::Iterator,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+    result::Result::{self, Err, Ok},
+    time::Duration,
+    write, writeln,
+};
+
+use deku::ctx::BitSize;
+use deku::no_std_io::{Read, Seek, Write};
+use deku::prelude::*;
+
+use crate::{Frame, DF, ICAO, KE};
+
+/// Most segments of a single ELM, from the 4 bit ND field
+pub const MAX_SEGMENTS: usize = 16;
+
+/// Bytes of the MD field of a single segment
+pub const SEGMENT_LEN: usize = 10;
+
+/// Fields of [`DF::CommDExtendedLengthMessage`] after the first five bits
+///
+/// Only the first two bits "11" identify the format, so the rest of the DF field holds the spare
+/// bit, KE and the first bit of ND. These are read from, and checked against, that DF field.
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(ctx = "id: u8")]
+pub struct CommD {
+    /// KE: Control, ELM
+    #[deku(reader = "Self::read_ke(id)", writer = "Self::write_ke(*ke, id)")]
+    pub ke: KE,
+    /// ND: Number of D-segment, 0..=15
+    #[deku(
+        reader = "Self::read_nd(deku::reader, id)",
+        writer = "Self::write_nd(deku::writer, *nd, id)"
+    )]
+    pub nd: u8,
+    /// MD: Message, Comm-D
+    pub md: [u8; SEGMENT_LEN],
+    /// AP: Address/Parity
+    pub parity: ICAO,
+}
+
+impl CommD {
+    fn read_ke(id: u8) -> Result<KE, DekuError> {
+        Ok(if id & 0b10 == 0 { KE::DownlinkELMTx } else { KE::UplinkELMAck })
+    }
+
+    /// Already written as part of the DF, only check that they match
+    fn write_ke(ke: KE, id: u8) -> Result<(), DekuError> {
+        if ke as u8 == (id >> 1) & 1 {
+            Ok(())
+        } else {
+            Err(DekuError::InvalidParam("KE does not match DF".into()))
+        }
+    }
+
+    fn read_nd<R: Read + Seek>(reader: &mut Reader<R>, id: u8) -> Result<u8, DekuError> {
+        let rest = u8::from_reader_with_ctx(reader, BitSize(3))?;
+        Ok(((id & 1) << 3) | rest)
+    }
+
+    /// Only the last 3 bits, the first is written as part of the DF
+    fn write_nd<W: Write + Seek>(writer: &mut Writer<W>, nd: u8, id: u8) -> Result<(), DekuError> {
+        if nd >> 3 != id & 1 {
+            return Err(DekuError::InvalidParam("ND does not match DF".into()));
+        }
+        (nd & 0b111).to_writer(writer, BitSize(3))
+    }
+}
+
+impl fmt::Display for CommD {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ke = match self.ke {
+            KE::DownlinkELMTx => "downlink ELM",
+            KE::UplinkELMAck => "uplink ELM acknowledgement",
+        };
+        writeln!(f, "  KE:            {ke}")?;
+        writeln!(f, "  Segment:       {}", self.nd)?;
+        write!(f, "  MD:            ")?;
+        for byte in self.md {
+            write!(f, "{byte:02x}")?;
+        }
+        writeln!(f)
+    }
+}
+
+/// Complete downlink ELM, from [`ElmReassembler::push`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedLengthMessage {
+    /// Address of the aircraft, from the AP field of the segments
+    pub icao: ICAO,
+    /// MD fields of every segment, ordered by ND
+    pub md: Vec<u8>,
+}
+
+impl ExtendedLengthMessage {
+    /// MD field of each segment
+    pub fn segments(&self) -> impl Iterator<Item = &[u8]> {
+        self.md.chunks(SEGMENT_LEN)
+    }
+}
+
+/// Segments received from one address
+#[derive(Debug)]
+struct Pending {
+    /// Time of the announcement or first segment
+    started: Duration,
+    /// Number of segments, from the [`crate::DownlinkRequest`]
+    expected: Option<usize>,
+    segments: [Option<[u8; SEGMENT_LEN]>; MAX_SEGMENTS],
+}
+
+impl Pending {
+    fn new(started: Duration) -> Self {
+        Self { started, expected: None, segments: [None; MAX_SEGMENTS] }
+    }
+
+    /// MD of all segments, if every announced segment was received
+    fn complete(&self) -> Option<Vec<u8>> {
+        let segments = self.segments.get(..self.expected?)?;
+        let mut md = Vec::with_capacity(segments.len() * SEGMENT_LEN);
+        for segment in segments {
+            md.extend_from_slice(&(*segment)?);
+        }
+        Some(md)
+    }
+}
+
+/// Collects [`DF::CommDExtendedLengthMessage`] segments into [`ExtendedLengthMessage`]s
+///
+/// The number of segments is only known from the [`crate::DownlinkRequest`] announcing the ELM, so
+/// replies carrying a DR field should be pushed along with the segments. Sequences that aren't
+/// completed within the timeout are dropped by [`Self::push`] at most one timeout later, so the
+/// pending sequences stay bounded without calling [`Self::expire`]. Time is whatever monotonic
+/// clock the caller uses.
+///
+/// ```rust
+/// use core::time::Duration;
+/// use hexlit::hex;
+/// use adsb_deku::comm_d::ElmReassembler;
+/// use adsb_deku::Frame;
+///
+/// let mut elm = ElmReassembler::new(Duration::from_secs(10));
+///
+/// // DF20 announcing a single segment (DR=16)
+/// let announce = Frame::from_bytes(&hex!("a0800000000000000000004756e6")).unwrap();
+/// assert_eq!(elm.push(&announce, Duration::from_secs(0)), None);
+///
+/// // ND=0
+/// let segment = Frame::from_bytes(&hex!("c0112233445566778899aa47e3e0")).unwrap();
+/// let message = elm.push(&segment, Duration::from_secs(1)).unwrap();
+/// assert_eq!(message.md, hex!("112233445566778899aa"));
+/// ```
+#[derive(Debug)]
+pub struct ElmReassembler {
+    timeout: Duration,
+    pending: BTreeMap<ICAO, Pending>,
+    /// Time of the next [`Self::expire`] from [`Self::push`]
+    next_expire: Duration,
+}
+
+impl ElmReassembler {
+    /// Drop incomplete sequences `timeout` after their announcement or first segment
+    #[must_use]
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, pending: BTreeMap::new(), next_expire: timeout }
+    }
+
+    /// Add a frame received at `now`, returning the ELM it completes
+    ///
+    /// Only downlink ELM segments and replies with a [`crate::DownlinkRequest`] of 16..=31 are
+    /// used, everything else is ignored.
+    pub fn push(&mut self, frame: &Frame, now: Duration) -> Option<ExtendedLengthMessage> {
+        // once per timeout, so sequences from addresses that are never heard again are dropped
+        if now >= self.next_expire {
+            self.expire(now);
+            self.next_expire = now.saturating_add(self.timeout);
+        }
+
+        let icao = frame.address()?.icao;
+        match &frame.df {
+            DF::SurveillanceAltitudeReply { dr, .. }
+            | DF::SurveillanceIdentityReply { dr, .. }
+            | DF::CommBAltitudeReply { dr, .. }
+            | DF::CommBIdentityReply { dr, .. } => {
+                let expected = dr.elm_segments()?;
+                let pending = self.pending(icao, now);
+                // a different length is a new ELM
+                if pending.expected.is_some_and(|n| n != expected) {
+                    *pending = Pending::new(now);
+                }
+                pending.expected = Some(expected);
+            }
+            DF::CommDExtendedLengthMessage { segment, .. } if segment.ke == KE::DownlinkELMTx => {
+                let pending = self.pending(icao, now);
+                pending.segments[usize::from(segment.nd)] = Some(segment.md);
+            }
+            _ => return None,
+        }
+
+        let md = self.pending.get(&icao)?.complete()?;
+        self.pending.remove(&icao);
+        Some(ExtendedLengthMessage { icao, md })
+    }
+
+    /// Drop every incomplete sequence that timed out at `now`
+    pub fn expire(&mut self, now: Duration) {
+        let timeout = self.timeout;
+        self.pending.retain(|_, pending| !Self::timed_out(timeout, pending, now));
+    }
+
+    /// Number of addresses with an incomplete sequence
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Sequence of `icao`, restarted if it timed out
+    fn pending(&mut self, icao: ICAO, now: Duration) -> &mut Pending {
+        let timeout = self.timeout;
+        let pending = self.pending.entry(icao).or_insert_with(|| Pending::new(now));
+        if Self::timed_out(timeout, pending, now) {
+            *pending = Pending::new(now);
+        }
+        pending
+    }
+
+    fn timed_out(timeout: Duration, pending: &Pending, now: Duration) -> bool {
+        now.saturating_sub(pending.started) > timeout
+    }
+}
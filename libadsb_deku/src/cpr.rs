@@ -18,6 +18,7 @@ use core::{
 #[cfg(not(feature = "alloc"))]
 use std::cmp;
 
+use crate::adsb::SurfacePosition;
 use crate::{Altitude, CPRFormat};
 
 const NZ: f64 = 15.0;
@@ -263,11 +264,163 @@ pub fn get_position(cpr_frames: (&Altitude, &Altitude)) -> Option<Position> {
 
     let lat = if latest_frame == even_frame { lat_even } else { lat_odd };
 
-    let (lat, lon) = get_lat_lon(lat, cpr_lon_even, cpr_lon_odd, &latest_frame.odd_flag);
+    let (lat, lon) = get_lat_lon(lat, cpr_lon_even, cpr_lon_odd, &latest_frame.odd_flag, 360.0);
 
     Some(Position { latitude: lat, longitude: lon })
 }
 
+/// Calculate Globally unambiguous surface position decoding
+///
+/// Using both an Odd and Even `SurfacePosition`, calculate the latitude/longitude. Surface
+/// positions are encoded in 90 degree zones, so of the two latitude and four longitude solutions
+/// the one closest to `reference`, such as the receiver location, is returned
+///
+/// reference: ICAO 9871 (D.2.4.7.7)
+#[must_use]
+pub fn get_surface_position(
+    cpr_frames: (&SurfacePosition, &SurfacePosition),
+    reference: Position,
+) -> Option<Position> {
+    let latest_frame = cpr_frames.1;
+    let (even_frame, odd_frame) = match cpr_frames {
+        (
+            even @ SurfacePosition { f: CPRFormat::Even, .. },
+            odd @ SurfacePosition { f: CPRFormat::Odd, .. },
+        )
+        | (
+            odd @ SurfacePosition { f: CPRFormat::Odd, .. },
+            even @ SurfacePosition { f: CPRFormat::Even, .. },
+        ) => (even, odd),
+        _ => return None,
+    };
+
+    let cpr_lat_even = f64::from(even_frame.lat_cpr) / CPR_MAX;
+    let cpr_lon_even = f64::from(even_frame.lon_cpr) / CPR_MAX;
+    let cpr_lat_odd = f64::from(odd_frame.lat_cpr) / CPR_MAX;
+    let cpr_lon_odd = f64::from(odd_frame.lon_cpr) / CPR_MAX;
+
+    let j = libm::floor(59.0 * cpr_lat_even - 60.0 * cpr_lat_odd + 0.5);
+
+    let mut lat_even = D_LAT_EVEN / 4.0 * (positive_mod(j, 60.0) + cpr_lat_even);
+    let mut lat_odd = D_LAT_ODD / 4.0 * (positive_mod(j, 59.0) + cpr_lat_odd);
+
+    // northern hemisphere solution, or the southern solution 90 degrees below
+    let lat = if latest_frame == even_frame { lat_even } else { lat_odd };
+    if reference.latitude < lat - 45.0 {
+        lat_even -= 90.0;
+        lat_odd -= 90.0;
+    }
+
+    // frames are from different longitude zones
+    if cpr_nl(lat_even) != cpr_nl(lat_odd) {
+        return None;
+    }
+
+    let lat = if latest_frame == even_frame { lat_even } else { lat_odd };
+
+    let (lat, lon) = get_lat_lon(lat, cpr_lon_even, cpr_lon_odd, &latest_frame.f, 90.0);
+
+    // closest of the four longitude solutions, 90 degrees apart
+    let mut lon = lon + 90.0 * libm::round((reference.longitude - lon) / 90.0);
+    if lon >= 180.0 {
+        lon -= 360.0;
+    } else if lon < -180.0 {
+        lon += 360.0;
+    }
+
+    Some(Position { latitude: lat, longitude: lon })
+}
+
+/// Calculate Locally unambiguous position decoding
+///
+/// Using a single `Altitude` and a `reference` position within 180 NM, such as the receiver or
+/// the last known position of the aircraft, calculate the latitude/longitude
+///
+/// reference: ICAO 9871 (D.2.4.7.6)
+#[must_use]
+pub fn get_position_local(frame: &Altitude, reference: Position) -> Option<Position> {
+    get_local(frame.lat_cpr, frame.lon_cpr, &frame.odd_flag, reference, 360.0)
+}
+
+/// Calculate Locally unambiguous surface position decoding
+///
+/// Using a single `SurfacePosition` and a `reference` position within 45 NM, such as the
+/// receiver or the last known position of the aircraft, calculate the latitude/longitude
+///
+/// reference: ICAO 9871 (D.2.4.7.6)
+#[must_use]
+pub fn get_surface_position_local(
+    frame: &SurfacePosition,
+    reference: Position,
+) -> Option<Position> {
+    get_local(frame.lat_cpr, frame.lon_cpr, &frame.f, reference, 90.0)
+}
+
+/// Local decoding, with `zone` as 360 degrees for airborne and 90 degrees for surface positions
+fn get_local(
+    lat_cpr: u32,
+    lon_cpr: u32,
+    cpr_format: &CPRFormat,
+    reference: Position,
+    zone: f64,
+) -> Option<Position> {
+    let i = if cpr_format == &CPRFormat::Even { 0 } else { 1 };
+    let cpr_lat = f64::from(lat_cpr) / CPR_MAX;
+    let cpr_lon = f64::from(lon_cpr) / CPR_MAX;
+
+    let d_lat = zone / (4.0 * NZ - i as f64);
+    let j = zone_index(reference.latitude / d_lat, cpr_lat);
+    let lat = d_lat * (j + cpr_lat);
+    if !(-90.0..=90.0).contains(&lat) {
+        return None;
+    }
+
+    let ni = cmp::max(cpr_nl(lat) - i, 1) as f64;
+    let d_lon = zone / ni;
+    let m = zone_index(reference.longitude / d_lon, cpr_lon);
+    let mut lon = d_lon * (m + cpr_lon);
+    if lon >= 180.0 {
+        lon -= 360.0;
+    } else if lon < -180.0 {
+        lon += 360.0;
+    }
+
+    Some(Position { latitude: lat, longitude: lon })
+}
+
+/// Index of the zone closest to the reference, with `zones` as the reference divided by the zone
+/// size. The fraction is taken from the same quotient to stay consistent on zone boundaries.
+fn zone_index(zones: f64, cpr: f64) -> f64 {
+    let index = libm::floor(zones);
+    index + libm::floor(0.5 + (zones - index) - cpr)
+}
+
+/// Encode a `Position` into the (latitude, longitude) CPR fields of an `Altitude` or
+/// `SurfacePosition`
+///
+/// The inverse of [`get_position`] and [`get_surface_position`], where `surface` selects the 90
+/// degree zones of surface positions
+///
+/// reference: ICAO 9871 (D.2.4.7.5)
+#[must_use]
+pub fn encode(position: Position, cpr_format: CPRFormat, surface: bool) -> (u32, u32) {
+    let zone = if surface { 90.0 } else { 360.0 };
+    let i = if cpr_format == CPRFormat::Even { 0 } else { 1 };
+
+    let d_lat = zone / (4.0 * NZ - i as f64);
+    let lat_zones = position.latitude / d_lat;
+    let lat_index = libm::floor(lat_zones);
+    let yz = libm::floor(CPR_MAX * (lat_zones - lat_index) + 0.5);
+    let r_lat = d_lat * (yz / CPR_MAX + lat_index);
+
+    let ni = cmp::max(cpr_nl(r_lat) - i, 1) as f64;
+    let d_lon = zone / ni;
+    let lon_zones = position.longitude / d_lon;
+    let xz = libm::floor(CPR_MAX * (lon_zones - libm::floor(lon_zones)) + 0.5);
+
+    (positive_mod(yz, CPR_MAX) as u32, positive_mod(xz, CPR_MAX) as u32)
+}
+
 fn positive_mod(a: f64, b: f64) -> f64 {
     let mut ret = a % b;
     if ret < 0.0 {
@@ -281,6 +434,7 @@ fn get_lat_lon(
     cpr_lon_even: f64,
     cpr_lon_odd: f64,
     cpr_format: &CPRFormat,
+    zone: f64,
 ) -> (f64, f64) {
     let (p, c) = if cpr_format == &CPRFormat::Even { (0, cpr_lon_even) } else { (1, cpr_lon_odd) };
     let ni = cmp::max(cpr_nl(lat) - p, 1) as f64;
@@ -291,7 +445,7 @@ fn get_lat_lon(
     // rem_euclid
     let r = positive_mod(m, ni);
 
-    let mut lon = (360.0 / ni) * (r + c);
+    let mut lon = (zone / ni) * (r + c);
     if lon >= 180.0 {
         lon -= 360.0;
     }
@@ -330,6 +484,158 @@ mod tests {
         assert!((position.longitude - 3.919_372_558_593_75).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn cpr_calculate_position_local() {
+        let even = Altitude {
+            odd_flag: CPRFormat::Even,
+            lat_cpr: 93000,
+            lon_cpr: 51372,
+            ..Altitude::default()
+        };
+        let reference = Position { latitude: 52.258, longitude: 3.918 };
+        let position = get_position_local(&even, reference).unwrap();
+        assert!((position.latitude - 52.257_202_148_437_5).abs() < f64::EPSILON);
+        assert!((position.longitude - 3.919_372_558_593_75).abs() < f64::EPSILON);
+
+        // same as the global decoding of the pair
+        let odd = Altitude {
+            odd_flag: CPRFormat::Odd,
+            lat_cpr: 74158,
+            lon_cpr: 50194,
+            ..Altitude::default()
+        };
+        let global = get_position((&even, &odd)).unwrap();
+        let local = get_position_local(&odd, reference).unwrap();
+        assert!((global.latitude - local.latitude).abs() < f64::EPSILON);
+        assert!((global.longitude - local.longitude).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cpr_calculate_surface_position_local() {
+        // *8c4841753a9a153237aef0f275be;
+        let surface = SurfacePosition {
+            mov: 41,
+            s: crate::adsb::StatusForGroundTrack::Valid,
+            trk: 33,
+            t: false,
+            f: CPRFormat::Odd,
+            lat_cpr: 39195,
+            lon_cpr: 110_320,
+        };
+        let reference = Position { latitude: 51.990, longitude: 4.375 };
+        let position = get_surface_position_local(&surface, reference).unwrap();
+        assert!((position.latitude - 52.320_560_519_978_15).abs() < 1e-9);
+        assert!((position.longitude - 4.735_735_212_053_572).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cpr_calculate_surface_position() {
+        // *8c4841753aab238733c8cd4020b1;
+        let even = SurfacePosition {
+            mov: 42,
+            s: crate::adsb::StatusForGroundTrack::Valid,
+            trk: 50,
+            t: false,
+            f: CPRFormat::Even,
+            lat_cpr: 115_609,
+            lon_cpr: 116_941,
+        };
+        // *8c4841753a8a35323faebdac702d;
+        let odd = SurfacePosition {
+            mov: 40,
+            s: crate::adsb::StatusForGroundTrack::Valid,
+            trk: 35,
+            t: false,
+            f: CPRFormat::Odd,
+            lat_cpr: 39199,
+            lon_cpr: 110_269,
+        };
+        let reference = Position { latitude: 51.990, longitude: 4.375 };
+        let position = get_surface_position((&even, &odd), reference).unwrap();
+        assert!((position.latitude - 52.320_607_072_215_96).abs() < 1e-9);
+        assert!((position.longitude - 4.734_734_671_456_474).abs() < 1e-9);
+
+        // the other quadrants
+        let reference = Position { latitude: -40.0, longitude: 4.375 };
+        let position = get_surface_position((&even, &odd), reference).unwrap();
+        assert!((position.latitude - (52.320_607_072_215_96 - 90.0)).abs() < 1e-9);
+        let reference = Position { latitude: 50.0, longitude: -80.0 };
+        let position = get_surface_position((&even, &odd), reference).unwrap();
+        assert!((position.longitude - (4.734_734_671_456_474 - 90.0)).abs() < 1e-9);
+        let reference = Position { latitude: 50.0, longitude: 179.0 };
+        let position = get_surface_position((&even, &odd), reference).unwrap();
+        assert!((position.longitude - (4.734_734_671_456_474 - 180.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cpr_encode() {
+        let position = Position { latitude: 52.257_202_148_437_5, longitude: 3.919_372_558_593_75 };
+        assert_eq!(encode(position, CPRFormat::Even, false), (93000, 51372));
+
+        let position =
+            Position { latitude: 52.320_607_072_215_96, longitude: 4.734_734_671_456_474 };
+        assert_eq!(encode(position, CPRFormat::Odd, true), (39199, 110_269));
+    }
+
+    #[test]
+    fn cpr_encode_decode() {
+        // low and high latitudes, poles, zone boundaries and the antimeridian
+        let latitudes =
+            [0.0, 10.470_471_3, 36.0, -45.5, 52.257, 59.954_592_77, 87.0, 89.9, 90.0, -90.0];
+        let longitudes = [0.0, -0.000_1, 3.919, 90.0, -135.5, 179.999_9, 180.0, -179.999_9];
+        for latitude in latitudes {
+            for longitude in longitudes {
+                let position = Position { latitude, longitude };
+                for surface in [false, true] {
+                    let zone = if surface { 90.0 } else { 360.0 };
+                    for cpr_format in [CPRFormat::Even, CPRFormat::Odd] {
+                        let (lat_cpr, lon_cpr) = encode(position, cpr_format, surface);
+                        let decoded = if surface {
+                            let frame = SurfacePosition {
+                                mov: 0,
+                                s: crate::adsb::StatusForGroundTrack::Invalid,
+                                trk: 0,
+                                t: false,
+                                f: cpr_format,
+                                lat_cpr,
+                                lon_cpr,
+                            };
+                            get_surface_position_local(&frame, position)
+                        } else {
+                            let frame = Altitude {
+                                odd_flag: cpr_format,
+                                lat_cpr,
+                                lon_cpr,
+                                ..Altitude::default()
+                            };
+                            get_position_local(&frame, position)
+                        }
+                        .unwrap();
+
+                        // within the resolution of the smallest zone
+                        let resolution = zone / CPR_MAX;
+                        assert!((decoded.latitude - latitude).abs() < resolution, "{position:?}");
+                        if latitude.abs() < 90.0 {
+                            let lon_difference =
+                                positive_mod(decoded.longitude - longitude + 180.0, 360.0) - 180.0;
+                            assert!(lon_difference.abs() < resolution, "{position:?}");
+                        }
+                    }
+                }
+            }
+        }
+
+        // global decoding of an even/odd pair
+        let position = Position { latitude: -33.946_1, longitude: 151.177_2 };
+        let (lat_cpr, lon_cpr) = encode(position, CPRFormat::Even, false);
+        let even = Altitude { odd_flag: CPRFormat::Even, lat_cpr, lon_cpr, ..Altitude::default() };
+        let (lat_cpr, lon_cpr) = encode(position, CPRFormat::Odd, false);
+        let odd = Altitude { odd_flag: CPRFormat::Odd, lat_cpr, lon_cpr, ..Altitude::default() };
+        let decoded = get_position((&even, &odd)).unwrap();
+        assert!((decoded.latitude - position.latitude).abs() < 360.0 / CPR_MAX);
+        assert!((decoded.longitude - position.longitude).abs() < 360.0 / CPR_MAX);
+    }
+
     #[test]
     fn cpr_calculate_position_high_lat() {
         let even = Altitude {
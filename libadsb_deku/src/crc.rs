@@ -290,3 +290,126 @@ pub fn modes_checksum(message: &[u8], bits: usize) -> result::Result<u32, DekuEr
 
     Ok(rem)
 }
+
+/// Bit errors found by [`correct`], as positions from the first bit of the message
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Correction {
+    One(usize),
+    Two(usize, usize),
+}
+
+/// Find the one or two bit errors of a `bits` long message with a non-zero `syndrome`
+///
+/// Only bits for which `correctable` returns true are considered, and bits of the syndrome outside
+/// of `mask` are ignored. `None` is returned if no correction, or more than one, is found.
+pub fn correct(
+    syndrome: u32,
+    bits: usize,
+    mask: u32,
+    max_errors: usize,
+    correctable: impl Fn(usize) -> bool,
+) -> Option<Correction> {
+    let syndromes: &[u32] = match bits {
+        56 => &SHORT_SYNDROMES,
+        112 => &LONG_SYNDROMES,
+        _ => return None,
+    };
+    let clears = |error: u32| (syndrome ^ error) & mask == 0;
+    let positions = || (0..bits).filter(|bit| correctable(*bit));
+
+    let mut found = None;
+    if max_errors >= 1 {
+        for i in positions() {
+            if clears(syndromes[i]) {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(Correction::One(i));
+            }
+        }
+    }
+    if found.is_some() || max_errors < 2 {
+        return found;
+    }
+
+    for i in positions() {
+        for j in positions().filter(|j| *j > i) {
+            if clears(syndromes[i] ^ syndromes[j]) {
+                if found.is_some() {
+                    return None;
+                }
+                found = Some(Correction::Two(i, j));
+            }
+        }
+    }
+    found
+}
+
+/// Generator polynomial of the parity, with its x^24 term
+const GENERATOR: u64 = 0x01ff_f409;
+
+/// Syndrome of a single bit error in a 56 bit message, by position
+static SHORT_SYNDROMES: [u32; 56] = bit_syndromes();
+
+/// Syndrome of a single bit error in a 112 bit message, by position
+static LONG_SYNDROMES: [u32; 112] = bit_syndromes();
+
+/// The checksum is linear, so the syndrome of an error is the checksum of the error bits: the
+/// parity bits themselves, and x^n mod the generator for the bit n places before the last
+const fn bit_syndromes<const BITS: usize>() -> [u32; BITS] {
+    let mut syndromes = [0; BITS];
+    let mut syndrome: u64 = 1;
+    let mut bit = BITS;
+    while bit > 0 {
+        bit -= 1;
+        syndromes[bit] = syndrome as u32;
+        syndrome <<= 1;
+        if syndrome & (1 << 24) != 0 {
+            syndrome ^= GENERATOR;
+        }
+    }
+    syndromes
+}
+
+/// Address sequence overlaid on the AP field of uplinks: the coefficients of x^47..x^24 of the
+/// address times the generator polynomial (3.1.2.3.3.2)
+pub fn uplink_overlay(address: u32) -> u32 {
+    let mut product: u64 = 0;
+    for bit in 0..24 {
+        if address & (1 << bit) != 0 {
+            product ^= GENERATOR << bit;
+        }
+    }
+    ((product >> 24) & 0x00ff_ffff) as u32
+}
+
+/// Address of an uplink [`modes_checksum`], the inverse of [`uplink_overlay`]
+pub fn uplink_address(overlay: u32) -> u32 {
+    // the x^24 term of the generator makes every bit of the overlay the matching address bit,
+    // plus the higher address bits times the rest of the generator
+    let mut address: u32 = 0;
+    for bit in (0..24).rev() {
+        let mut value = (overlay >> bit) & 1;
+        for higher in bit + 1..24 {
+            value ^= (address >> higher) & ((GENERATOR >> (24 + bit - higher)) as u32) & 1;
+        }
+        address |= value << bit;
+    }
+    address
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_syndromes_match_checksum() {
+        for (bits, syndromes) in [(56, &SHORT_SYNDROMES[..]), (112, &LONG_SYNDROMES[..])] {
+            for (bit, syndrome) in syndromes.iter().enumerate() {
+                let mut message = [0_u8; 14];
+                message[bit / 8] = 0x80 >> (bit % 8);
+                assert_eq!(modes_checksum(&message, bits).unwrap(), *syndrome, "bit {bit}");
+            }
+        }
+    }
+}
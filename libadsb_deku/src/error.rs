@@ -0,0 +1,59 @@
+//! Errors of [`crate::Frame::from_bytes_validated`]
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::ToString};
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    fmt,
+    fmt::Debug,
+    prelude::rust_2021::derive,
+    write,
+};
+
+use deku::DekuError;
+
+/// Decoding error, by cause
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Error {
+    /// Non-zero parity of DF17/DF18, or parity of DF11 that isn't an interrogator code
+    BadParity { syndrome: u32 },
+    /// DF without a decoder
+    UnsupportedDownlinkFormat(u8),
+    /// Message is shorter than its DF, in bytes
+    Truncated { needed: usize, got: usize },
+    /// Field that could not be decoded
+    InvalidField { field: Cow<'static, str> },
+}
+
+impl Error {
+    /// Map a [`DekuError`] from decoding a message of `got` bytes
+    pub(crate) fn from_deku(error: DekuError, got: usize) -> Self {
+        match error {
+            DekuError::Incomplete(need) => Self::Truncated { needed: got + need.byte_size(), got },
+            DekuError::Parse(field)
+            | DekuError::InvalidParam(field)
+            | DekuError::Assertion(field) => Self::InvalidField { field },
+            DekuError::AssertionNoStr => Self::InvalidField { field: "assertion".into() },
+            DekuError::IdVariantNotFound => Self::InvalidField { field: "unknown id".into() },
+            error => Self::InvalidField { field: error.to_string().into() },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadParity { syndrome } => write!(f, "bad parity, syndrome: {syndrome:06x}"),
+            Self::UnsupportedDownlinkFormat(df) => write!(f, "unsupported downlink format: {df}"),
+            Self::Truncated { needed, got } => {
+                write!(f, "truncated message, needed {needed} bytes, got {got}")
+            }
+            Self::InvalidField { field } => write!(f, "invalid field: {field}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,199 @@
+//! Decoding of the most common DF17 messages from fixed bit positions, without deku
+//!
+//! Airborne positions (TC 9..=18), airborne velocities (TC 19) and identifications (TC 1..=4)
+//! make up most of the extended squitter traffic, and are decoded here straight from the bytes
+//! into the same [`ME`] values that [`crate::Frame::from_bytes`] returns.
+
+#[cfg(feature = "alloc")]
+use core::{
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+};
+
+use crate::adsb::{
+    AirborneVelocity, AirborneVelocitySubType, AirspeedDecoding, GroundSpeedDecoding,
+    Identification, TypeCoding, VerticalRateSource, ADSB, ME,
+};
+use crate::{
+    crc, Altitude, CPRFormat, Callsign, Capability, Frame, Sign, SurveillanceStatus, DF, ICAO,
+};
+
+/// Altitude in feet of every 12 bit AC field, see [`Altitude::decode`]
+static AC12: [Option<u16>; 4096] = {
+    let mut table = [None; 4096];
+    let mut num = 0;
+    while num < table.len() {
+        table[num] = Altitude::decode(num as u32);
+        num += 1;
+    }
+    table
+};
+
+/// Decode a DF17 message with one of the type codes above, `None` for anything else
+pub(crate) fn decode(buf: &[u8]) -> Option<Frame> {
+    let buf: &[u8; 14] = buf.get(..14)?.try_into().ok()?;
+    if buf[0] >> 3 != 17 {
+        return None;
+    }
+
+    let mut bytes = [0; 8];
+    bytes[1..].copy_from_slice(&buf[4..11]);
+    let me = Bits(u64::from_be_bytes(bytes));
+    let me = match me.get(0, 5) as u8 {
+        id @ 1..=4 => identification(id, me),
+        id @ 9..=18 => ME::AirbornePositionBaroAltitude { id, altitude: altitude(me) },
+        19 => ME::AirborneVelocity(velocity(me)?),
+        _ => return None,
+    };
+
+    let adsb = ADSB {
+        capability: capability(buf[0] & 0b111),
+        icao: ICAO([buf[1], buf[2], buf[3]]),
+        me,
+        pi: ICAO([buf[11], buf[12], buf[13]]),
+    };
+    let crc = crc::modes_checksum(buf, 112).ok()?;
+    Some(Frame { df: DF::ADSB(adsb), crc })
+}
+
+/// The 56 bits of the ME field
+#[derive(Copy, Clone)]
+struct Bits(u64);
+
+impl Bits {
+    /// `len` bits starting at bit `start` of the ME field
+    fn get(self, start: u32, len: u32) -> u64 {
+        (self.0 >> (56 - start - len)) & ((1 << len) - 1)
+    }
+
+    fn bit(self, start: u32) -> bool {
+        self.get(start, 1) != 0
+    }
+
+    fn sign(self, start: u32) -> Sign {
+        if self.bit(start) {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+}
+
+fn capability(ca: u8) -> Capability {
+    match ca {
+        0 => Capability::AG_UNCERTAIN,
+        1..=3 => Capability::Reserved(ca),
+        4 => Capability::AG_GROUND,
+        5 => Capability::AG_AIRBORNE,
+        6 => Capability::AG_UNCERTAIN2,
+        _ => Capability::AG_UNCERTAIN3,
+    }
+}
+
+fn identification(id: u8, me: Bits) -> ME {
+    let tc = match id {
+        1 => TypeCoding::D,
+        2 => TypeCoding::C,
+        3 => TypeCoding::B,
+        _ => TypeCoding::A,
+    };
+    let mut cn = Callsign::default();
+    for i in 0..8 {
+        cn.push_code(me.get(8 + i * 6, 6) as u8);
+    }
+    let identification = Identification { tc, ca: me.get(5, 3) as u8, cn };
+    ME::AircraftIdentification { id, identification }
+}
+
+fn altitude(me: Bits) -> Altitude {
+    Altitude {
+        ss: match me.get(5, 2) {
+            0 => SurveillanceStatus::NoCondition,
+            1 => SurveillanceStatus::PermanentAlert,
+            2 => SurveillanceStatus::TemporaryAlert,
+            _ => SurveillanceStatus::SPICondition,
+        },
+        saf_or_imf: me.get(7, 1) as u8,
+        alt: AC12[me.get(8, 12) as usize],
+        t: me.bit(20),
+        odd_flag: if me.bit(21) { CPRFormat::Odd } else { CPRFormat::Even },
+        lat_cpr: me.get(22, 17) as u32,
+        lon_cpr: me.get(39, 17) as u32,
+    }
+}
+
+/// Subtypes 1..=4, the reserved subtypes are left to deku
+fn velocity(me: Bits) -> Option<AirborneVelocity> {
+    let st = me.get(5, 3) as u8;
+    let sub_type = match st {
+        1 | 2 => AirborneVelocitySubType::GroundSpeedDecoding(GroundSpeedDecoding {
+            ew_sign: me.sign(13),
+            ew_vel: me.get(14, 10) as u16,
+            ns_sign: me.sign(24),
+            ns_vel: me.get(25, 10) as u16,
+        }),
+        3 | 4 => AirborneVelocitySubType::AirspeedDecoding(AirspeedDecoding {
+            status_heading: me.get(13, 1) as u8,
+            mag_heading: me.get(14, 10) as u16,
+            airspeed_type: me.get(24, 1) as u8,
+            airspeed: (me.get(25, 10) as u16).checked_sub(1),
+        }),
+        _ => return None,
+    };
+    let gnss_baro_diff = me.get(49, 7) as u16;
+    Some(AirborneVelocity {
+        st,
+        nac_v: me.get(8, 5) as u8,
+        sub_type,
+        vrate_src: if me.bit(35) {
+            VerticalRateSource::GeometricAltitude
+        } else {
+            VerticalRateSource::BarometricPressureAltitude
+        },
+        vrate_sign: me.sign(36),
+        vrate_value: me.get(37, 9) as u16,
+        reverved: me.get(46, 2) as u8,
+        gnss_sign: me.sign(48),
+        gnss_baro_diff: if gnss_baro_diff > 1 { (gnss_baro_diff - 1) * 25 } else { 0 },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// xorshift, enough to cover every field without a dependency
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn fast_matches_deku() {
+        let mut state = 0x2545_f491_4f6c_dd1d;
+        let mut decoded = 0;
+        for i in 0..10_000 {
+            let mut buf = [0; 14];
+            for chunk in buf.chunks_mut(8) {
+                let len = chunk.len();
+                chunk.copy_from_slice(&next(&mut state).to_be_bytes()[..len]);
+            }
+            let tc = [1, 2, 3, 4, 9, 11, 13, 18, 19][i % 9];
+            buf[0] = (17 << 3) | (buf[0] & 0b111);
+            buf[4] = (tc << 3) | (buf[4] & 0b111);
+
+            let deku = Frame::from_bytes(&buf).unwrap();
+            if let Some(fast) = decode(&buf) {
+                assert_eq!(fast.df, deku.df, "{buf:02x?}");
+                assert_eq!(fast.crc, deku.crc, "{buf:02x?}");
+                decoded += 1;
+            } else {
+                // reserved velocity subtypes only
+                assert_eq!(tc, 19, "{buf:02x?}");
+            }
+        }
+        assert!(decoded > 9_000);
+    }
+}
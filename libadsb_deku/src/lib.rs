@@ -19,7 +19,21 @@ See [`rsadsb.github.io`] for more details.
 | 19       | [`Extended Squitter(Military)`]     | 3.1.2.8.8   |
 | 20       | [`Comm-B Altitude Reply`]           | 3.1.2.6.6   |
 | 21       | [`Comm-B Identity Reply`]           | 3.1.2.6.8   |
-| 24..=31  | [`ModeS Extended Squitter`]          | 3.1.2.7.3   |
+| 24..=31  | [`Comm-D Extended Length Message`]  | 3.1.2.7.3   |
+
+# Uplink Format Support
+Interrogations are decoded with [`Uplink::from_bytes()`].
+
+|  [`UF`]  |  Name                               |  Section    |
+| -------- | ----------------------------------- | ----------- |
+| 0        | Short Air-Air Surveillance          | 3.1.2.8.1   |
+| 4        | Surveillance, Altitude Request      | 3.1.2.6.2   |
+| 5        | Surveillance, Identity Request      | 3.1.2.6.4   |
+| 11       | Mode S Only All-Call                | 3.1.2.5.2.1 |
+| 16       | Long Air-Air Surveillance           | 3.1.2.8.3   |
+| 20       | Comm-A, Altitude Request            | 3.1.2.6.2   |
+| 21       | Comm-A, Identity Request            | 3.1.2.6.4   |
+| 24..=31  | Comm-C Extended Length Message      | 3.1.2.7.1   |
 
 # [`Comm-B Altitude Reply`] and [`Comm-B Identity Reply`] Comm-B Support
 
@@ -28,6 +42,11 @@ See [`rsadsb.github.io`] for more details.
 | (0,0)     | [`Empty`]                               |             |
 | (1,0)     | [`Data Link Capability`]                | A-2-16      |
 | (2,0)     | [`Aircraft Identification`]             | A-2-32      |
+| (4,0)     | [`Selected Vertical Intention`]         | A-2-64      |
+| (4,4)     | [`Meteorological Routine Air Report`]   | A-2-68      |
+| (4,5)     | [`Meteorological Hazard Report`]        | A-2-69      |
+| (5,0)     | [`Track and Turn Report`]               | A-2-80      |
+| (6,0)     | [`Heading and Speed Report`]            | A-2-96      |
 
 # [`Extended Squitter(ADS-B)`] and [`Extended Squitter(TIS-B)`] Type Code Support
 
@@ -39,7 +58,7 @@ See [`rsadsb.github.io`] for more details.
 | 9..=18              | [`ME::AirbornePositionBaroAltitude`]   |
 | 19                  | [`ME::AirborneVelocity`]               |
 | 20..=22             | [`ME::AirbornePositionGNSSAltitude`]   |
-| 23                  | [`ME::Reserved0`]                      |
+| 23                  | [`ME::TestMessage`]                    |
 | 24                  | [`ME::SurfaceSystemStatus`]            |
 | 25..=27             | [`ME::Reserved1`]                      |
 | 28                  | [`ME::AircraftStatus`]                 |
@@ -76,6 +95,8 @@ The [`apps/`] directory of the project repository contains programs `radar` and
 different `adsb_deku` uses. See the [`README.md`] for examples of use.
 
 [`DF`]: crate::DF
+[`UF`]: crate::uplink::UF
+[`Uplink::from_bytes()`]: crate::uplink::Uplink::from_bytes
 [`ME`]: crate::adsb::ME
 [`BDS`]: crate::bds::BDS
 [`Short Air-Air Surveillance`]: crate::DF::ShortAirAirSurveillance
@@ -88,18 +109,23 @@ different `adsb_deku` uses. See the [`README.md`] for examples of use.
 [`Extended Squitter(Military)`]: crate::DF::ExtendedQuitterMilitaryApplication
 [`Comm-B Altitude Reply`]: crate::DF::CommBAltitudeReply
 [`Comm-B Identity Reply`]: crate::DF::CommBIdentityReply
-[`ModeS Extended Squitter`]: crate::DF::ModeSExtendedSquitter
+[`Comm-D Extended Length Message`]: crate::DF::CommDExtendedLengthMessage
 
 [`Empty`]: crate::bds::BDS::Empty
 [`Data Link Capability`]: crate::bds::BDS::DataLinkCapability
 [`Aircraft Identification`]: crate::bds::BDS::AircraftIdentification
+[`Selected Vertical Intention`]: crate::bds::BDS::SelectedVerticalIntention
+[`Meteorological Routine Air Report`]: crate::bds::BDS::MeteorologicalRoutineAirReport
+[`Meteorological Hazard Report`]: crate::bds::BDS::MeteorologicalHazardReport
+[`Track and Turn Report`]: crate::bds::BDS::TrackAndTurnReport
+[`Heading and Speed Report`]: crate::bds::BDS::HeadingAndSpeedReport
 [`ME::NoPosition`]: crate::adsb::ME::NoPosition
 [`ME::AircraftIdentification`]: crate::adsb::ME::AircraftIdentification
 [`ME::SurfacePosition`]: crate::adsb::ME::SurfacePosition
 [`ME::AirbornePositionBaroAltitude`]: crate::adsb::ME::AirbornePositionBaroAltitude
 [`ME::AirborneVelocity`]: crate::adsb::ME::AirborneVelocity
 [`ME::AirbornePositionGNSSAltitude`]: crate::adsb::ME::AirbornePositionGNSSAltitude
-[`ME::Reserved0`]: crate::adsb::ME::Reserved0
+[`ME::TestMessage`]: crate::adsb::ME::TestMessage
 [`ME::SurfaceSystemStatus`]: crate::adsb::ME::SurfaceSystemStatus
 [`ME::Reserved1`]: crate::adsb::ME::Reserved1
 [`ME::AircraftStatus`]: crate::adsb::ME::AircraftStatus
@@ -121,7 +147,7 @@ different `adsb_deku` uses. See the [`README.md`] for examples of use.
 extern crate alloc;
 
 #[cfg(feature = "alloc")]
-use alloc::{fmt, format, string::String, string::ToString, vec, vec::Vec};
+use alloc::{fmt, format, string::ToString, vec, vec::Vec};
 #[cfg(feature = "alloc")]
 use core::{
     clone::Clone,
@@ -130,7 +156,6 @@ use core::{
     default::Default,
     fmt::Debug,
     hash::Hash,
-    iter::IntoIterator,
     marker::Copy,
     prelude::rust_2021::derive,
     result,
@@ -138,35 +163,58 @@ use core::{
     write, writeln,
 };
 
+pub mod acas;
+pub mod accuracy;
 pub mod adsb;
 pub mod bds;
+pub mod comm_d;
 pub mod cpr;
 mod crc;
+mod error;
+mod fast;
 mod mode_ac;
+mod peek;
+pub mod uplink;
+
+pub use error::Error;
+pub use peek::{peek, Header};
 
 #[doc = include_str!("../README.md")]
 mod readme_test {}
 
-use adsb::{ControlField, ADSB};
+use acas::MV;
+use adsb::{ControlField, MilitaryApplication, ADSB};
 use bds::BDS;
+use comm_d::CommD;
 use deku::ctx::{BitSize, Endian};
-use deku::no_std_io::{Cursor, Read, Seek};
+use deku::no_std_io::{Cursor, Read, Seek, Write};
 use deku::prelude::*;
+use uplink::{CodeLabel, InterrogatorCode};
 
 /// Every read to this struct will be saved into an internal cache. This is to keep the cache
 /// around for the crc without reading from the buffer twice!
 struct ReaderCrc<R: Read + Seek> {
     reader: R,
-    cache: Vec<u8>,
+    /// Up to the longest message, anything after it isn't part of the crc
+    cache: [u8; 14],
+    len: usize,
     just_seeked: bool,
 }
 
+impl<R: Read + Seek> ReaderCrc<R> {
+    fn new(reader: R) -> Self {
+        Self { reader, cache: [0; 14], len: 0, just_seeked: false }
+    }
+}
+
 impl<R: Read + Seek> Read for ReaderCrc<R> {
     fn read(&mut self, buf: &mut [u8]) -> deku::no_std_io::Result<usize> {
         let n = self.reader.read(buf);
         if !self.just_seeked {
             if let Ok(n) = n {
-                self.cache.extend_from_slice(&buf[..n]);
+                let end = (self.len + n).min(self.cache.len());
+                self.cache[self.len..end].copy_from_slice(&buf[..end - self.len]);
+                self.len = end;
             }
         }
         self.just_seeked = false;
@@ -197,8 +245,29 @@ impl Frame {
         Self::from_reader(cursor)
     }
 
+    /// Decode as [`Self::from_bytes`] does, with a faster path for the most common messages
+    ///
+    /// DF17 airborne positions (TC 9..=18), airborne velocities (TC 19) and identifications
+    /// (TC 1..=4) are read from their fixed bit positions instead of through deku, and everything
+    /// else falls back to [`Self::from_bytes`]. Both return the same [`Frame`].
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::Frame;
+    ///
+    /// let bytes = hex!("8da2c1bd587ba2adb31799cb802b");
+    /// let frame = Frame::from_bytes_fast(&bytes).unwrap();
+    /// assert_eq!(frame.df, Frame::from_bytes(&bytes).unwrap().df);
+    /// ```
+    pub fn from_bytes_fast(buf: &[u8]) -> Result<Frame, DekuError> {
+        match fast::decode(buf) {
+            Some(frame) => Ok(frame),
+            None => Self::from_bytes(buf),
+        }
+    }
+
     pub fn from_reader<R: Read + Seek>(r: R) -> Result<Frame, DekuError> {
-        let mut reader_crc = ReaderCrc { reader: r, cache: vec![], just_seeked: false };
+        let mut reader_crc = ReaderCrc::new(r);
         let mut reader = Reader::new(&mut reader_crc);
         let df = DF::from_reader_with_ctx(&mut reader, ())?;
 
@@ -206,18 +275,232 @@ impl Frame {
 
         Ok(Self { df, crc })
     }
+
+    /// Decode, rejecting messages with bad parity
+    ///
+    /// Unlike [`Self::from_bytes`], DF17 and DF18 with a non-zero [`Self::crc`] are rejected with
+    /// [`Error::BadParity`], as is DF11 with a [`Self::crc`] that isn't an interrogator code, see
+    /// [`Self::interrogator_code`]. The parity of other DFs is overlaid with the address and can't
+    /// be checked without knowing it.
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::{Error, Frame};
+    ///
+    /// let mut bytes = hex!("8da2c1bd587ba2adb31799cb802b");
+    /// assert!(Frame::from_bytes_validated(&bytes).is_ok());
+    /// bytes[6] ^= 0x10;
+    /// assert_eq!(
+    ///     Frame::from_bytes_validated(&bytes).unwrap_err(),
+    ///     Error::BadParity { syndrome: 0x30ace0 }
+    /// );
+    /// ```
+    pub fn from_bytes_validated(buf: &[u8]) -> Result<Frame, Error> {
+        let Some(first) = buf.first() else {
+            return Err(Error::Truncated { needed: 7, got: 0 });
+        };
+        let df = first >> 3;
+        if !matches!(df, 0 | 4 | 5 | 11 | 16..=21 | 24..=31) {
+            return Err(Error::UnsupportedDownlinkFormat(df));
+        }
+        let needed = if df & 0x10 != 0 { 14 } else { 7 };
+        if buf.len() < needed {
+            return Err(Error::Truncated { needed, got: buf.len() });
+        }
+
+        let frame = Self::from_bytes(buf).map_err(|e| Error::from_deku(e, buf.len()))?;
+        let mask = if df == 11 { 0x00ff_ff80 } else { 0x00ff_ffff };
+        if matches!(df, 11 | 17 | 18) && frame.crc & mask != 0 {
+            return Err(Error::BadParity { syndrome: frame.crc });
+        }
+        Ok(frame)
+    }
+
+    /// Decode, correcting up to `max_bits` (1 or 2) bit errors found from the CRC syndrome
+    ///
+    /// Only DF17 and DF18, and DF11 with a single bit error, carry parity that isn't overlaid with
+    /// an address and can be corrected. Other messages are decoded as [`Self::from_bytes`] does.
+    /// To guard against false corrections, the DF field and the announced address are never
+    /// changed, since a corrected address could point to an aircraft that was never seen, and a
+    /// syndrome matching more than one correction is rejected.
+    ///
+    /// Returns the frame with the positions of the flipped bits, counting from the first bit of
+    /// the message, which is empty if the message was received without errors.
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::Frame;
+    ///
+    /// let mut bytes = hex!("8da2c1bd587ba2adb31799cb802b");
+    /// bytes[6] ^= 0x10;
+    /// let (frame, flipped) = Frame::from_bytes_corrected(&bytes, 1).unwrap();
+    /// assert_eq!(flipped, [51]);
+    /// assert_eq!(frame.crc, 0);
+    /// ```
+    pub fn from_bytes_corrected(
+        buf: &[u8],
+        max_bits: usize,
+    ) -> Result<(Frame, Vec<usize>), DekuError> {
+        const DF_BITS: usize = 5;
+        const AA_BITS: core::ops::Range<usize> = 8..32;
+
+        let Some(first) = buf.first() else {
+            return Err(DekuError::Incomplete(NeedSize::new(8)));
+        };
+        let (bits, mask, max_bits) = match first >> 3 {
+            // the PI of DF11 is overlaid with the 7 bit interrogator code
+            11 => (56, 0x00ff_ff80, max_bits.min(1)),
+            17 | 18 => (112, 0x00ff_ffff, max_bits.min(2)),
+            _ => return Ok((Self::from_bytes(buf)?, vec![])),
+        };
+        let len = bits / 8;
+        if buf.len() < len {
+            return Err(DekuError::Incomplete(NeedSize::new(bits)));
+        }
+
+        let syndrome = crc::modes_checksum(buf, bits)?;
+        if syndrome & mask == 0 {
+            return Ok((Self::from_bytes(buf)?, vec![]));
+        }
+
+        let flipped = match crc::correct(syndrome, bits, mask, max_bits, |bit| {
+            bit >= DF_BITS && !AA_BITS.contains(&bit)
+        }) {
+            Some(crc::Correction::One(bit)) => vec![bit],
+            Some(crc::Correction::Two(first, second)) => vec![first, second],
+            None => {
+                return Err(DekuError::Parse(
+                    format!("CRC errors can't be corrected, syndrome: {syndrome:06x}").into(),
+                ))
+            }
+        };
+
+        let mut message = [0_u8; 14];
+        message[..len].copy_from_slice(&buf[..len]);
+        for bit in &flipped {
+            message[bit / 8] ^= 0x80 >> (bit % 8);
+        }
+        Ok((Self::from_bytes(&message[..len])?, flipped))
+    }
+
+    /// Encode into the 56 or 112 bit message
+    ///
+    /// The last 24 bits (AP or PI) are computed from the rest of the message with
+    /// `crc::modes_checksum` and then overlaid with [`Self::crc`], so that decoding the returned
+    /// bytes gives back the same `crc`. Any address/parity value stored within [`DF`] is ignored.
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::Frame;
+    ///
+    /// let bytes = hex!("8da2c1bd587ba2adb31799cb802b");
+    /// let frame = Frame::from_bytes(&bytes).unwrap();
+    /// assert_eq!(frame.to_bytes().unwrap(), bytes);
+    /// ```
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut bytes = self.df.to_bytes()?;
+
+        let len = Self::bit_len(&self.df) / 8;
+        if bytes.len() > len {
+            return Err(DekuError::InvalidParam("DF is larger than the message length".into()));
+        }
+        // pad any DF that doesn't define the full message
+        bytes.resize(len, 0);
+
+        // with the parity bits cleared, the checksum is the parity of the rest of the message
+        bytes[len - 3..].fill(0);
+        let parity = crc::modes_checksum(&bytes, len * 8)? ^ self.crc;
+        bytes[len - 3..].copy_from_slice(&parity.to_be_bytes()[1..]);
+
+        Ok(bytes)
+    }
 }
 
 impl Frame {
-    /// Read rest as CRC bits
-    fn read_crc<R: Read + Seek>(
-        df: &DF,
-        reader: &mut ReaderCrc<R>,
-    ) -> result::Result<u32, DekuError> {
+    /// Aircraft address and where it was read from, `None` if the DF doesn't carry one
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::{AddressSource, Frame};
+    ///
+    /// let bytes = hex!("200012b0d96e39");
+    /// let frame = Frame::from_bytes(&bytes).unwrap();
+    /// let address = frame.address().unwrap();
+    /// assert_eq!(address.icao.to_string(), "a3ecce");
+    /// assert_eq!(address.source, AddressSource::Parity);
+    /// ```
+    #[must_use]
+    pub fn address(&self) -> Option<Address> {
+        // the 3 bits after the DF that select the address, CF of DF18 and AF of DF19
+        let (df, field) = match &self.df {
+            DF::TisB { cf, .. } => (18, cf.t.deku_id().ok()?),
+            DF::ExtendedQuitterMilitaryApplication { af: MilitaryApplication::Raw(af, _) } => {
+                (19, *af)
+            }
+            // segments are the only DF with more than one id, all of them with a parity address
+            df => (df.deku_id().unwrap_or(24), 0),
+        };
+        let me = match &self.df {
+            DF::TisB { cf, .. } => cf.me.to_bytes().unwrap_or_default(),
+            _ => vec![],
+        };
+        let source = AddressSource::from_header(df, (df << 3) | field, &me)?;
+        let icao = match (&self.df, source) {
+            (_, AddressSource::Parity) => {
+                let bytes = self.crc.to_be_bytes();
+                ICAO([bytes[1], bytes[2], bytes[3]])
+            }
+            (DF::ADSB(adsb), _) => adsb.icao,
+            (DF::AllCallReply { icao, .. }, _)
+            | (
+                DF::ExtendedQuitterMilitaryApplication {
+                    af: MilitaryApplication::ExtendedSquitter { icao, .. },
+                },
+                _,
+            ) => *icao,
+            (DF::TisB { cf, .. }, _) => cf.aa,
+            _ => return None,
+        };
+        Some(Address { icao, source })
+    }
+
+    /// Interrogator the DF11 replied to, from the code overlaid with its PI
+    ///
+    /// `None` for other DFs, for bad parity, or for an unassigned code label.
+    ///
+    /// ```rust
+    /// use hexlit::hex;
+    /// use adsb_deku::uplink::InterrogatorCode;
+    /// use adsb_deku::Frame;
+    ///
+    /// let frame = Frame::from_bytes(&hex!("5da58fd4561b39")).unwrap();
+    /// assert_eq!(frame.interrogator_code(), Some(InterrogatorCode::II(0)));
+    /// ```
+    #[must_use]
+    pub fn interrogator_code(&self) -> Option<InterrogatorCode> {
+        if !matches!(self.df, DF::AllCallReply { .. }) || self.crc & 0x00ff_ff80 != 0 {
+            return None;
+        }
+        // 3 bit CL followed by the 4 bit IC
+        let cl = match (self.crc >> 4) & 0b111 {
+            0 => CodeLabel::II,
+            1 => CodeLabel::SI1To15,
+            2 => CodeLabel::SI16To31,
+            3 => CodeLabel::SI32To47,
+            4 => CodeLabel::SI48To63,
+            cl => CodeLabel::Unassigned(cl as u8),
+        };
+        cl.interrogator_code((self.crc & 0x0f) as u8)
+    }
+}
+
+impl Frame {
+    /// Length of message in bits, from the DF
+    fn bit_len(df: &DF) -> usize {
         const MODES_LONG_MSG_BYTES: usize = 14;
         const MODES_SHORT_MSG_BYTES: usize = 7;
 
-        let bit_len = if let Ok(id) = df.deku_id() {
+        if let Ok(id) = df.deku_id() {
             if id & 0x10 != 0 {
                 MODES_LONG_MSG_BYTES * 8
             } else {
@@ -226,15 +509,25 @@ impl Frame {
         } else {
             // In this case, it's the DF::CommD, which has multiple ids
             MODES_LONG_MSG_BYTES * 8
-        };
+        }
+    }
+
+    /// Read rest as CRC bits
+    fn read_crc<R: Read + Seek>(
+        df: &DF,
+        reader: &mut ReaderCrc<R>,
+    ) -> result::Result<u32, DekuError> {
+        let bit_len = Self::bit_len(df);
 
-        if bit_len > reader.cache.len() * 8 {
-            let mut buf = vec![];
-            reader.read_to_end(&mut buf).unwrap();
-            reader.cache.append(&mut buf);
+        while bit_len > reader.len * 8 {
+            let mut buf = [0; 14];
+            match reader.read(&mut buf[..bit_len / 8 - reader.len]) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => (),
+            }
         }
 
-        let crc = crc::modes_checksum(&reader.cache, bit_len)?;
+        let crc = crc::modes_checksum(&reader.cache[..reader.len], bit_len)?;
         Ok(crc)
     }
 }
@@ -243,24 +536,20 @@ impl fmt::Display for Frame {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let crc = self.crc;
         match &self.df {
-            DF::ShortAirAirSurveillance { altitude, .. } => {
+            DF::ShortAirAirSurveillance { vs, altitude, .. } => {
                 writeln!(f, " Short Air-Air Surveillance")?;
                 writeln!(f, "  ICAO Address:  {crc:06x} (Mode S / ADS-B)")?;
-                if altitude.0 > 0 {
-                    let altitude = altitude.0;
-                    writeln!(f, "  Air/Ground:    airborne?")?;
-                    writeln!(f, "  Altitude:      {altitude} ft barometric")?;
-                } else {
-                    writeln!(f, "  Air/Ground:    ground")?;
+                writeln!(f, "  Air/Ground:    {}", vertical_status(*vs))?;
+                if altitude.feet().is_some() {
+                    writeln!(f, "  Altitude:      {altitude} barometric")?;
                 }
             }
             DF::SurveillanceAltitudeReply { fs, ac, .. } => {
                 writeln!(f, " Surveillance, Altitude Reply")?;
                 writeln!(f, "  ICAO Address:  {crc:06x} (Mode S / ADS-B)")?;
                 writeln!(f, "  Air/Ground:    {fs}")?;
-                if ac.0 > 0 {
-                    let altitude = ac.0;
-                    writeln!(f, "  Altitude:      {altitude} ft barometric")?;
+                if ac.feet().is_some() {
+                    writeln!(f, "  Altitude:      {ac} barometric")?;
                 }
             }
             DF::SurveillanceIdentityReply { fs, id, .. } => {
@@ -275,17 +564,14 @@ impl fmt::Display for Frame {
                 writeln!(f, "  ICAO Address:  {icao} (Mode S / ADS-B)")?;
                 writeln!(f, "  Air/Ground:    {capability}")?;
             }
-            DF::LongAirAir { altitude, .. } => {
+            DF::LongAirAir { vs, altitude, mv, .. } => {
                 writeln!(f, " Long Air-Air ACAS")?;
                 writeln!(f, "  ICAO Address:  {crc:06x} (Mode S / ADS-B)")?;
-                // TODO the airborne? should't be static
-                if altitude.0 > 0 {
-                    let altitude = altitude.0;
-                    writeln!(f, "  Air/Ground:    airborne?")?;
-                    writeln!(f, "  Baro altitude: {altitude} ft")?;
-                } else {
-                    writeln!(f, "  Air/Ground:    ground")?;
+                writeln!(f, "  Air/Ground:    {}", vertical_status(*vs))?;
+                if altitude.feet().is_some() {
+                    writeln!(f, "  Baro altitude: {altitude}")?;
                 }
+                write!(f, "{mv}")?;
             }
             DF::ADSB(adsb) => {
                 write!(f, "{}", adsb.to_string("(Mode S / ADS-B)")?)?;
@@ -293,34 +579,51 @@ impl fmt::Display for Frame {
             DF::TisB { cf, .. } => {
                 write!(f, "{cf}")?;
             }
-            // TODO
-            DF::ExtendedQuitterMilitaryApplication { .. } => {}
+            DF::ExtendedQuitterMilitaryApplication { af } => {
+                write!(f, "{af}")?;
+            }
             DF::CommBAltitudeReply { bds, alt, .. } => {
                 writeln!(f, " Comm-B, Altitude Reply")?;
                 writeln!(f, "  ICAO Address:  {crc:x?} (Mode S / ADS-B)")?;
-                let altitude = alt.0;
-                writeln!(f, "  Altitude:      {altitude} ft")?;
-                write!(f, "  {bds}")?;
+                writeln!(f, "  Altitude:      {alt}")?;
+                // shown as the register it can only be, the frame itself keeps the raw MB
+                match bds.unambiguous() {
+                    Some(inferred) => write!(f, "  {inferred}")?,
+                    None => write!(f, "  {bds}")?,
+                }
             }
             DF::CommBIdentityReply { id, bds, .. } => {
                 writeln!(f, " Comm-B, Identity Reply")?;
                 writeln!(f, "    ICAO Address:  {crc:x?} (Mode S / ADS-B)")?;
                 writeln!(f, "    Squawk:        {id:x?}")?;
-                write!(f, "    {bds}")?;
+                match bds.unambiguous() {
+                    Some(inferred) => write!(f, "    {inferred}")?,
+                    None => write!(f, "    {bds}")?,
+                }
             }
-            DF::ModeSExtendedSquitter { .. } => {
-                writeln!(f, " Mode S Extended Squitter Message")?;
-                writeln!(f, "    ICAO Address:     {crc:x?} (Mode S / ADS-B)")?;
+            DF::CommDExtendedLengthMessage { segment, .. } => {
+                writeln!(f, " Comm-D Extended Length Message")?;
+                writeln!(f, "  ICAO Address:  {crc:06x} (Mode S / ADS-B)")?;
+                write!(f, "{segment}")?;
             }
         }
         Ok(())
     }
 }
 
+/// VS: Vertical Status of [`DF::ShortAirAirSurveillance`] and [`DF::LongAirAir`]
+fn vertical_status(vs: u8) -> &'static str {
+    if vs == 0 {
+        "airborne?"
+    } else {
+        "ground"
+    }
+}
+
 /// Downlink Format (3.1.2.3.2.1.2)
 ///
 /// Starting with 5 bits, decode the rest of the message as the correct data packets
-#[derive(Debug, PartialEq, DekuRead, Clone)]
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "5")]
 pub enum DF {
@@ -419,8 +722,7 @@ pub enum DF {
         /// AC: altitude code
         altitude: AC13Field,
         /// MV: message, acas
-        #[deku(count = "7")]
-        mv: Vec<u8>,
+        mv: MV,
         /// AP: address, parity
         parity: ICAO,
     },
@@ -440,9 +742,8 @@ pub enum DF {
     /// 19: Extended Squitter Military Application, Downlink Format 19 (3.1.2.8.8)
     #[deku(id = "19")]
     ExtendedQuitterMilitaryApplication {
-        /// Reserved
-        #[deku(bits = "3")]
-        af: u8,
+        /// AF: Application Field, followed by its message
+        af: MilitaryApplication,
     },
 
     /// 20: COMM-B Altitude Reply (3.1.2.6.6)
@@ -473,7 +774,8 @@ pub enum DF {
         #[deku(
             bits = "13",
             endian = "big",
-            map = "|squawk: u32| -> Result<_, DekuError> {Ok(mode_ac::decode_id13_field(squawk))}"
+            map = "|squawk: u32| -> Result<_, DekuError> {Ok(mode_ac::decode_id13_field(squawk))}",
+            writer = "mode_ac::encode_id13_field(*id).to_writer(deku::writer, (Endian::Big, BitSize(13)))"
         )]
         id: u32,
         /// MB Message, Comm-B
@@ -482,43 +784,25 @@ pub enum DF {
         parity: ICAO,
     },
 
-    /// | DF | Name                   |
-    /// | -- | -----------------------|
-    /// | 24 | Comm-B                 |
-    /// | 25 | Comm-C ELM             |
-    /// | 26 | Comm-D ELM             |
-    /// | 27 | TisB/ADS-R             |
-    /// | 28 | Reserved               |
-    /// | 29 | Reserved               |
-    /// | 30 | ACAS/TCAS(Coordination)|
-    /// | 31 | ACAS/TCAS(Resolution)  |
+    /// 24..=31: Comm-D Extended Length Message segment, Downlink Format 24 (3.1.2.7.3)
     #[deku(id_pat = "24..=31")]
-    ModeSExtendedSquitter {
-        /// id
-        df: u8,
-
-        /// CA: Capability
-        capability: Capability,
-        icao: ICAO,
-
-        #[deku(bits = 5)]
-        type_code: u8,
-
-        #[deku(bits = 51)]
-        adsb_data: u64,
-
-        parity: ICAO,
+    CommDExtendedLengthMessage {
+        /// "11", spare bit, KE and the first bit of ND
+        id: u8,
+        /// KE, ND, MD and AP
+        #[deku(ctx = "*id")]
+        segment: CommD,
     },
 }
 
 /// Latitude, Longitude and Altitude information
-#[derive(Debug, PartialEq, Eq, DekuRead, Default, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Altitude {
     pub ss: SurveillanceStatus,
     #[deku(bits = "1")]
     pub saf_or_imf: u8,
-    #[deku(reader = "Self::read(deku::reader)")]
+    #[deku(reader = "Self::read(deku::reader)", writer = "Self::write(deku::writer, *alt)")]
     pub alt: Option<u16>,
     /// UTC sync or not
     #[deku(bits = "1")]
@@ -549,61 +833,76 @@ impl Altitude {
     /// `decodeAC12Field`
     fn read<R: Read + Seek>(reader: &mut Reader<R>) -> Result<Option<u16>, DekuError> {
         let num = u32::from_reader_with_ctx(reader, (Endian::Big, BitSize(12)))?;
+        Ok(Self::decode(num))
+    }
+
+    /// Altitude in feet of the 12 bit AC field, `None` if unavailable or invalid
+    pub(crate) const fn decode(num: u32) -> Option<u16> {
         let q = num & 0x10;
 
         if q > 0 {
             let n = ((num & 0x0fe0) >> 1) | (num & 0x000f);
             let n = n * 25;
             if n > 1000 {
-                // TODO: maybe replace with Result->Option
-                Ok(u16::try_from(n - 1000).ok())
+                Some((n - 1000) as u16)
             } else {
-                Ok(None)
+                None
             }
         } else {
             let mut n = ((num & 0x0fc0) << 1) | (num & 0x003f);
             n = mode_ac::decode_id13_field(n);
             if let Ok(n) = mode_ac::mode_a_to_mode_c(n) {
-                Ok(u16::try_from(n * 100).ok())
+                if n >= 0 && n * 100 <= u16::MAX as i32 {
+                    Some((n * 100) as u16)
+                } else {
+                    None
+                }
             } else {
-                Ok(None)
+                None
             }
         }
     }
+
+    /// Inverse of [`Self::read`], 25ft resolution is used when the altitude allows for it
+    fn write<W: Write + Seek>(writer: &mut Writer<W>, alt: Option<u16>) -> Result<(), DekuError> {
+        let num = match alt {
+            None => 0,
+            Some(alt) => {
+                let n = (u32::from(alt) + 1000) / 25;
+                if alt > 0 && alt % 25 == 0 && n <= 0x07ff {
+                    ((n & 0x07f0) << 1) | 0x10 | (n & 0x000f)
+                } else {
+                    let n = AC13Field::encode_gillham(i32::from(alt))?;
+                    ((n & 0x1f80) >> 1) | (n & 0x003f)
+                }
+            }
+        };
+        num.to_writer(writer, (Endian::Big, BitSize(12)))
+    }
 }
 
 /// SPI Condition
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "2")]
 pub enum SurveillanceStatus {
+    #[default]
     NoCondition = 0,
     PermanentAlert = 1,
     TemporaryAlert = 2,
     SPICondition = 3,
 }
 
-impl Default for SurveillanceStatus {
-    fn default() -> Self {
-        Self::NoCondition
-    }
-}
-
 /// Even / Odd
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum CPRFormat {
+    #[default]
     Even = 0,
     Odd = 1,
 }
 
-impl Default for CPRFormat {
-    fn default() -> Self {
-        Self::Even
-    }
-}
-
 impl fmt::Display for CPRFormat {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -618,7 +917,7 @@ impl fmt::Display for CPRFormat {
 }
 
 /// Positive / Negative
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum Sign {
@@ -650,9 +949,12 @@ impl fmt::Display for Sign {
 }
 
 /// 13 bit identity code
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct IdentityCode(#[deku(reader = "Self::read(deku::reader)")] pub u16);
+pub struct IdentityCode(
+    #[deku(reader = "Self::read(deku::reader)", writer = "Self::write(deku::writer, *field_0)")]
+    pub u16,
+);
 
 impl IdentityCode {
     fn read<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<u16, DekuError> {
@@ -679,10 +981,32 @@ impl IdentityCode {
         let num: u16 = ((a << 12) | (b << 8) | (c << 4) | d) as u16;
         Ok(num)
     }
+
+    fn write<W: Write + Seek>(writer: &mut Writer<W>, num: u16) -> result::Result<(), DekuError> {
+        let num = u32::from(num);
+        let a = (num >> 12) & 0b111;
+        let b = (num >> 8) & 0b111;
+        let c = (num >> 4) & 0b111;
+        let d = num & 0b111;
+
+        let num = ((c & 0b001) << 12)
+            | ((a & 0b001) << 11)
+            | ((c & 0b010) << 9)
+            | ((a & 0b010) << 8)
+            | ((c & 0b100) << 6)
+            | ((a & 0b100) << 5)
+            | ((b & 0b001) << 5)
+            | ((d & 0b001) << 4)
+            | ((b & 0b010) << 2)
+            | ((d & 0b010) << 1)
+            | ((b & 0b100) >> 1)
+            | ((d & 0b100) >> 2);
+        num.to_writer(writer, (Endian::Big, BitSize(13)))
+    }
 }
 
 /// ICAO Address; Mode S transponder code
-#[derive(Debug, PartialEq, Eq, PartialOrd, DekuRead, Hash, Copy, Clone, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, DekuRead, DekuWrite, Hash, Copy, Clone, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ICAO(pub [u8; 3]);
 
@@ -706,8 +1030,69 @@ impl core::str::FromStr for ICAO {
     }
 }
 
+/// Aircraft address of a [`Frame`], from [`Frame::address`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Address {
+    pub icao: ICAO,
+    pub source: AddressSource,
+}
+
+/// Where the address of a [`Frame`] was read from
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressSource {
+    /// AA: Address Announced of DF11, DF17 and DF18 with an ICAO address
+    Announced,
+    /// AP: Address/Parity of DF0/4/5/16/20/21/24, the address is the remainder of the parity.
+    /// Only correct if the message was received without errors.
+    Parity,
+    /// AA of DF18 with a non-ICAO address: anonymous, ground vehicles or TIS-B track files, from
+    /// the CF or the IMF bit
+    NonIcao,
+}
+
+impl AddressSource {
+    /// Where the address of a message is read from, from its DF, first byte and the ME field of
+    /// DF18. `None` if the message doesn't carry an address
+    pub(crate) fn from_header(df: u8, first: u8, me: &[u8]) -> Option<Self> {
+        match df {
+            11 | 17 => Some(Self::Announced),
+            // AF: Application Field, 0 for the layout of DF17
+            19 if first & 0b111 == 0 => Some(Self::Announced),
+            // CF: Control Field, see `ControlFieldType`
+            18 => match first & 0b111 {
+                0 => Some(Self::Announced),
+                cf @ (2 | 3 | 6) if !Self::imf(cf, me) => Some(Self::Announced),
+                1 | 2 | 3 | 5 | 6 => Some(Self::NonIcao),
+                _ => None,
+            },
+            0 | 4 | 5 | 16 | 20 | 21 | 24..=31 => Some(Self::Parity),
+            _ => None,
+        }
+    }
+
+    /// IMF: ICAO/Mode A Flag of TIS-B and ADS-R, set for a track file or anonymous address
+    ///
+    /// reference: ICAO 9871 (B.3)
+    fn imf(cf: u8, me: &[u8]) -> bool {
+        // ME bit, counting from 1
+        let bit = |n: usize| me.get((n - 1) / 8).is_some_and(|b| b & (0x80 >> ((n - 1) % 8)) != 0);
+        if cf == 3 {
+            // coarse format has no type code
+            return bit(1);
+        }
+        match me.first().map(|b| b >> 3) {
+            Some(5..=8) => bit(21),
+            Some(9..=18 | 20..=22) => bit(8),
+            Some(19) => bit(9),
+            _ => false,
+        }
+    }
+}
+
 /// Type of `DownlinkRequest`
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "5")]
 pub enum DownlinkRequest {
@@ -727,8 +1112,19 @@ pub enum DownlinkRequest {
     Unknown(u8),
 }
 
+impl DownlinkRequest {
+    /// Number of segments of the announced downlink ELM, from a DR of 16..=31
+    #[must_use]
+    pub fn elm_segments(&self) -> Option<usize> {
+        match self {
+            Self::Unknown(dr @ 16..=31) => Some(usize::from(dr - 15)),
+            _ => None,
+        }
+    }
+}
+
 /// Uplink / Downlink
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "1")]
 pub enum KE {
@@ -736,7 +1132,7 @@ pub enum KE {
     UplinkELMAck = 1,
 }
 
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UtilityMessage {
     #[deku(bits = "4")]
@@ -745,7 +1141,7 @@ pub struct UtilityMessage {
 }
 
 /// Message Type
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "2")]
 pub enum UtilityMessageType {
@@ -756,7 +1152,7 @@ pub enum UtilityMessageType {
 }
 
 /// Airborne / Ground and SPI
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 pub enum FlightStatus {
@@ -789,48 +1185,129 @@ impl fmt::Display for FlightStatus {
 }
 
 /// 13 bit encoded altitude
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct AC13Field(#[deku(reader = "Self::read(deku::reader)")] pub u16);
+pub enum AC13Field {
+    /// All zeros, altitude not available
+    Unavailable,
+    /// Q bit set, altitude in feet with 25 ft resolution
+    Feet25(i32),
+    /// Gillham coded, altitude in feet with 100 ft resolution, -1200..=126700
+    Feet100(i32),
+    /// M bit set, altitude in meters
+    Meters(u16),
+    /// Gillham code that is not a valid altitude
+    Invalid(u16),
+}
 
-impl AC13Field {
-    // TODO Add unit
-    fn read<R: Read + Seek>(reader: &mut Reader<R>) -> result::Result<u16, DekuError> {
+impl<'a> DekuReader<'a> for AC13Field {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> result::Result<Self, DekuError> {
         let num = u16::from_reader_with_ctx(reader, (Endian::Big, BitSize(13)))?;
+        Ok(Self::decode(num))
+    }
+}
 
-        // Handle invalid or special codes
-        if num == 0 || num == 0b1111111111111 {
-            return Ok(0);
+impl DekuWriter for AC13Field {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        _: (),
+    ) -> result::Result<(), DekuError> {
+        let num = self.encode()?;
+        num.to_writer(writer, (Endian::Big, BitSize(13)))
+    }
+}
+
+impl fmt::Display for AC13Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => write!(f, "unavailable"),
+            Self::Feet25(alt) | Self::Feet100(alt) => write!(f, "{alt} ft"),
+            Self::Meters(alt) => write!(f, "{alt} m"),
+            Self::Invalid(_) => write!(f, "invalid"),
+        }
+    }
+}
+
+impl AC13Field {
+    /// Altitude in feet, converted from meters if needed. `None` if unavailable or invalid
+    #[must_use]
+    pub fn feet(&self) -> Option<i32> {
+        match *self {
+            Self::Feet25(alt) | Self::Feet100(alt) => Some(alt),
+            Self::Meters(alt) => Some(libm::round(f64::from(alt) / 0.3048) as i32),
+            Self::Unavailable | Self::Invalid(_) => None,
+        }
+    }
+
+    /// `decodeAC13Field`
+    fn decode(num: u16) -> Self {
+        if num == 0 {
+            return Self::Unavailable;
+        }
+        if num == 0b1_1111_1111_1111 {
+            return Self::Invalid(num);
         }
 
         let m_bit = num & 0x0040;
         let q_bit = num & 0x0010;
 
         if m_bit != 0 {
-            // TODO: read altitude when meter is selected
-            Ok(0)
+            Self::Meters(((num & 0x1f80) >> 1) | (num & 0x003f))
         } else if q_bit != 0 {
             let n = ((num & 0x1f80) >> 2) | ((num & 0x0020) >> 1) | (num & 0x000f);
-            let n = n * 25;
-            if n > 1000 {
-                Ok(n - 1000)
-            } else {
-                // TODO: add error
-                Ok(0)
-            }
+            Self::Feet25(i32::from(n) * 25 - 1000)
         } else {
-            // TODO 11 bit gillham coded altitude
-            if let Ok(n) = mode_ac::mode_a_to_mode_c(mode_ac::decode_id13_field(num as u32)) {
-                Ok((100 * n) as u16)
-            } else {
-                Ok(0)
+            match mode_ac::mode_a_to_mode_c(mode_ac::decode_id13_field(u32::from(num))) {
+                Ok(n) => Self::Feet100(n * 100),
+                Err(_) => Self::Invalid(num),
+            }
+        }
+    }
+
+    /// Inverse of [`Self::decode`]
+    fn encode(&self) -> result::Result<u32, DekuError> {
+        match *self {
+            Self::Unavailable => Ok(0),
+            Self::Feet25(alt) => {
+                let n = u32::try_from(alt + 1000)
+                    .ok()
+                    .filter(|n| n % 25 == 0 && n / 25 <= 0x07ff)
+                    .ok_or_else(|| {
+                        DekuError::InvalidParam("altitude not in 25ft increments".into())
+                    })?
+                    / 25;
+                Ok(((n & 0x07e0) << 2) | ((n & 0x0010) << 1) | 0x10 | (n & 0x000f))
             }
+            Self::Feet100(alt) => Self::encode_gillham(alt),
+            Self::Meters(alt) => {
+                let alt = u32::from(alt);
+                // all ones is decoded as invalid
+                if alt >= 0x0fff {
+                    return Err(DekuError::InvalidParam("altitude out of range".into()));
+                }
+                Ok(((alt & 0x0fc0) << 1) | 0x40 | (alt & 0x003f))
+            }
+            Self::Invalid(num) => Ok(u32::from(num)),
         }
     }
+
+    /// 100ft gillham coded altitude into the 13 bit field
+    fn encode_gillham(alt: i32) -> result::Result<u32, DekuError> {
+        if alt % 100 != 0 {
+            return Err(DekuError::InvalidParam("altitude not in 100ft increments".into()));
+        }
+        let mode_a =
+            mode_ac::mode_c_to_mode_a(alt / 100).map_err(|e| DekuError::InvalidParam(e.into()))?;
+        Ok(mode_ac::encode_id13_field(mode_a))
+    }
 }
 
 /// Transponder level and additional information (3.1.2.5.2.2.1)
-#[derive(Debug, PartialEq, Eq, DekuRead, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[deku(id_type = "u8", bits = "3")]
 #[allow(non_camel_case_types)]
@@ -879,17 +1356,105 @@ impl fmt::Display for Capability {
 
 const CHAR_LOOKUP: &[u8; 64] = b"#ABCDEFGHIJKLMNOPQRSTUVWXYZ##### ###############0123456789######";
 
-pub(crate) fn aircraft_identification_read<R: Read + Seek>(
-    reader: &mut Reader<R>,
-) -> Result<String, DekuError> {
-    let mut chars = vec![];
-    for _ in 0..=6 {
-        let c = <u8>::from_reader_with_ctx(reader, BitSize(6))?;
+/// Aircraft identification (callsign) of up to 8 characters, without the space padding
+#[derive(Debug, PartialEq, Eq, Hash, Default, Copy, Clone)]
+pub struct Callsign {
+    chars: [u8; 8],
+    len: u8,
+}
+
+impl Callsign {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // only ever holds characters of `CHAR_LOOKUP`
+        core::str::from_utf8(&self.chars[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    /// Append the character of a 6 bit code, skipping the space padding
+    pub(crate) fn push_code(&mut self, c: u8) {
         if c != 32 {
-            chars.push(c);
+            self.chars[usize::from(self.len)] = CHAR_LOOKUP[usize::from(c & 0x3f)];
+            self.len += 1;
+        }
+    }
+}
+
+impl fmt::Display for Callsign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl core::str::FromStr for Callsign {
+    type Err = Error;
+
+    /// Characters must be upper case letters, digits or spaces
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut callsign = Self::default();
+        if s.len() > callsign.chars.len() {
+            return Err(Error::InvalidField { field: "aircraft identification length".into() });
+        }
+        for c in s.bytes() {
+            if c == b'#' || !CHAR_LOOKUP.contains(&c) {
+                return Err(Error::InvalidField { field: "aircraft identification".into() });
+            }
+            callsign.chars[usize::from(callsign.len)] = c;
+            callsign.len += 1;
         }
+        Ok(callsign)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Callsign {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Callsign {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = Callsign;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an aircraft identification")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+pub(crate) fn aircraft_identification_read<R: Read + Seek>(
+    reader: &mut Reader<R>,
+) -> Result<Callsign, DekuError> {
+    let mut callsign = Callsign::default();
+    for _ in 0..8 {
+        callsign.push_code(<u8>::from_reader_with_ctx(reader, BitSize(6))?);
+    }
+
+    Ok(callsign)
+}
+
+/// Inverse of [`aircraft_identification_read`], padding with spaces up to 8 characters
+pub(crate) fn aircraft_identification_write<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    cn: &Callsign,
+) -> Result<(), DekuError> {
+    for c in cn.as_str().bytes().chain(core::iter::repeat(b' ')).take(8) {
+        let Some(c) = CHAR_LOOKUP.iter().position(|b| *b == c) else {
+            return Err(DekuError::InvalidParam("invalid aircraft identification".into()));
+        };
+        (c as u8).to_writer(writer, BitSize(6))?;
     }
-    let encoded = chars.into_iter().map(|b| CHAR_LOOKUP[b as usize] as char).collect::<String>();
 
-    Ok(encoded)
+    Ok(())
 }
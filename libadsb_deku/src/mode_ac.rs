@@ -6,7 +6,7 @@ use core::{
     result::Result::{Err, Ok},
 };
 
-pub(crate) fn decode_id13_field(id13_field: u32) -> u32 {
+pub(crate) const fn decode_id13_field(id13_field: u32) -> u32 {
     let mut hex_gillham: u32 = 0;
 
     if id13_field & 0x1000 != 0 {
@@ -50,7 +50,7 @@ pub(crate) fn decode_id13_field(id13_field: u32) -> u32 {
     hex_gillham
 }
 
-pub(crate) fn mode_a_to_mode_c(mode_a: u32) -> result::Result<u32, &'static str> {
+pub(crate) const fn mode_a_to_mode_c(mode_a: u32) -> result::Result<i32, &'static str> {
     let mut five_hundreds: u32 = 0;
     let mut one_hundreds: u32 = 0;
 
@@ -112,10 +112,115 @@ pub(crate) fn mode_a_to_mode_c(mode_a: u32) -> result::Result<u32, &'static str>
         one_hundreds = 6 - one_hundreds;
     }
 
-    let n = (five_hundreds * 5) + one_hundreds;
-    if n >= 13 {
-        Ok(n - 13)
-    } else {
-        Err("Invalid altitude")
+    // lowest altitude is -1200ft
+    Ok(((five_hundreds * 5) + one_hundreds) as i32 - 13)
+}
+
+/// Inverse of [`decode_id13_field`]
+pub(crate) fn encode_id13_field(hex_gillham: u32) -> u32 {
+    let mut id13_field: u32 = 0;
+
+    if hex_gillham & 0x0010 != 0 {
+        id13_field |= 0x1000;
+    } // C1 = Bit 12
+    if hex_gillham & 0x1000 != 0 {
+        id13_field |= 0x0800;
+    } // A1 = Bit 11
+    if hex_gillham & 0x0020 != 0 {
+        id13_field |= 0x0400;
+    } // C2 = Bit 10
+    if hex_gillham & 0x2000 != 0 {
+        id13_field |= 0x0200;
+    } // A2 = Bit  9
+    if hex_gillham & 0x0040 != 0 {
+        id13_field |= 0x0100;
+    } // C4 = Bit  8
+    if hex_gillham & 0x4000 != 0 {
+        id13_field |= 0x0080;
+    } // A4 = Bit  7
+    if hex_gillham & 0x0100 != 0 {
+        id13_field |= 0x0020;
+    } // B1 = Bit  5
+    if hex_gillham & 0x0001 != 0 {
+        id13_field |= 0x0010;
+    } // D1 = Bit  4
+    if hex_gillham & 0x0200 != 0 {
+        id13_field |= 0x0008;
+    } // B2 = Bit  3
+    if hex_gillham & 0x0002 != 0 {
+        id13_field |= 0x0004;
+    } // D2 = Bit  2
+    if hex_gillham & 0x0400 != 0 {
+        id13_field |= 0x0002;
+    } // B4 = Bit  1
+    if hex_gillham & 0x0004 != 0 {
+        id13_field |= 0x0001;
+    } // D4 = Bit  0
+
+    id13_field
+}
+
+/// Inverse of [`mode_a_to_mode_c`], returning the gillham coded altitude in 100ft increments
+pub(crate) fn mode_c_to_mode_a(mode_c: i32) -> result::Result<u32, &'static str> {
+    let n = u32::try_from(mode_c + 13).ok().filter(|n| *n >= 1).ok_or("Invalid altitude")?;
+    let five_hundreds = (n - 1) / 5;
+    let mut one_hundreds = n - five_hundreds * 5;
+
+    // five_hundreds only has 8 bits (D2, D4, A1, A2, A4, B1, B2, B4)
+    if five_hundreds > 0xff {
+        return Err("Invalid altitude");
     }
+
+    // Correct order of one_hundreds.
+    if five_hundreds & 1 != 0 {
+        one_hundreds = 6 - one_hundreds;
+    }
+
+    // Add 7s to OneHundreds (Make 5->7).
+    if one_hundreds == 5 {
+        one_hundreds = 7;
+    }
+
+    // both are gray coded
+    let five_hundreds = five_hundreds ^ (five_hundreds >> 1);
+    let one_hundreds = one_hundreds ^ (one_hundreds >> 1);
+
+    let mut mode_a: u32 = 0;
+
+    if one_hundreds & 0b100 != 0 {
+        mode_a |= 0x0010;
+    } // C1
+    if one_hundreds & 0b010 != 0 {
+        mode_a |= 0x0020;
+    } // C2
+    if one_hundreds & 0b001 != 0 {
+        mode_a |= 0x0040;
+    } // C4
+
+    if five_hundreds & 0x80 != 0 {
+        mode_a |= 0x0002;
+    } // D2
+    if five_hundreds & 0x40 != 0 {
+        mode_a |= 0x0004;
+    } // D4
+    if five_hundreds & 0x20 != 0 {
+        mode_a |= 0x1000;
+    } // A1
+    if five_hundreds & 0x10 != 0 {
+        mode_a |= 0x2000;
+    } // A2
+    if five_hundreds & 0x08 != 0 {
+        mode_a |= 0x4000;
+    } // A4
+    if five_hundreds & 0x04 != 0 {
+        mode_a |= 0x0100;
+    } // B1
+    if five_hundreds & 0x02 != 0 {
+        mode_a |= 0x0200;
+    } // B2
+    if five_hundreds & 0x01 != 0 {
+        mode_a |= 0x0400;
+    } // B4
+
+    Ok(mode_a)
 }
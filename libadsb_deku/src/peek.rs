@@ -0,0 +1,79 @@
+//! Header of a message from fixed bit positions, without decoding the full [`crate::Frame`]
+
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    fmt::Debug,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+};
+
+use crate::{crc, Address, AddressSource, ICAO};
+
+/// Fields of a message, from [`peek`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    /// DF: Downlink Format
+    pub df: u8,
+    /// Aircraft address, as [`crate::Frame::address`] returns it
+    pub address: Option<Address>,
+    /// Type code of the ME field of DF17, DF18 and DF19 with AF=0
+    pub type_code: Option<u8>,
+    /// Subtype of the ME field of DF17, DF18 and DF19 with AF=0, for type codes 19 and 23..=31
+    pub subtype: Option<u8>,
+    /// Length of the message in bytes
+    pub len: usize,
+}
+
+/// Read the [`Header`] of a message, `None` if `buf` is shorter than its DF
+///
+/// Only the fixed bit positions are read, plus the parity for DFs with an address overlaid on
+/// it, so frames can be filtered before paying for [`crate::Frame::from_bytes`].
+///
+/// ```rust
+/// use hexlit::hex;
+/// use adsb_deku::peek;
+///
+/// let bytes = hex!("8da2c1bd587ba2adb31799cb802b");
+/// let header = peek(&bytes).unwrap();
+/// assert_eq!(header.df, 17);
+/// assert_eq!(header.address.unwrap().icao.to_string(), "a2c1bd");
+/// assert_eq!(header.type_code, Some(11));
+/// ```
+#[must_use]
+pub fn peek(buf: &[u8]) -> Option<Header> {
+    let df = buf.first()? >> 3;
+    let len = if df & 0x10 != 0 { 14 } else { 7 };
+    if buf.len() < len {
+        return None;
+    }
+
+    let aa = ICAO([buf[1], buf[2], buf[3]]);
+    let source = AddressSource::from_header(df, buf[0], buf.get(4..11).unwrap_or_default());
+    let address = source.and_then(|source| {
+        let icao = if source == AddressSource::Parity {
+            let bytes = crc::modes_checksum(buf, len * 8).ok()?.to_be_bytes();
+            ICAO([bytes[1], bytes[2], bytes[3]])
+        } else {
+            aa
+        };
+        Some(Address { icao, source })
+    });
+
+    let (type_code, subtype) = if df == 17 || df == 18 || (df == 19 && buf[0] & 0b111 == 0) {
+        let type_code = buf[4] >> 3;
+        let subtype = match type_code {
+            29 => Some((buf[4] >> 1) & 0b11),
+            19 | 23..=31 => Some(buf[4] & 0b111),
+            _ => None,
+        };
+        (Some(type_code), subtype)
+    } else {
+        (None, None)
+    };
+
+    Some(Header { df, address, type_code, subtype, len })
+}
@@ -0,0 +1,705 @@
+//! Mode S interrogations, the 1030 MHz uplink answered by the [`crate::DF`] replies
+//!
+//! reference: ICAO Annex 10 Vol IV 3.1.2.3 and 3.1.2.6
+
+#[cfg(feature = "alloc")]
+use alloc::{fmt, vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::{
+    clone::Clone,
+    cmp::{Eq, PartialEq},
+    fmt::Debug,
+    marker::Copy,
+    option::Option::{self, None, Some},
+    prelude::rust_2021::derive,
+    result::Result::{self, Err, Ok},
+    write, writeln,
+};
+
+use deku::ctx::BitSize;
+use deku::no_std_io::{Cursor, Read, Seek, Write};
+use deku::prelude::*;
+
+use crate::{crc, ICAO};
+
+/// Uplink Mode S Packet
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uplink {
+    /// Starting with 5 bit identifier, decode packet
+    pub uf: UF,
+    /// Calculated from all bits, the address overlaid on the AP field, see [`Self::address`]
+    pub crc: u32,
+}
+
+impl Uplink {
+    /// Interrogation of `address`, with the [`Self::crc`] encoding it
+    #[must_use]
+    pub fn new(uf: UF, address: ICAO) -> Self {
+        let address = u32::from_be_bytes([0, address.0[0], address.0[1], address.0[2]]);
+        Self { uf, crc: crc::uplink_overlay(address) }
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Result<Uplink, DekuError> {
+        let mut cursor = Cursor::new(buf);
+        let mut reader = Reader::new(&mut cursor);
+        let uf = UF::from_reader_with_ctx(&mut reader, ())?;
+        let crc = crc::modes_checksum(buf, Self::bit_len(buf[0] >> 3))?;
+        Ok(Self { uf, crc })
+    }
+
+    /// Encode into the 56 or 112 bit message, see [`crate::Frame::to_bytes`]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut bytes = self.uf.to_bytes()?;
+
+        let len = Self::bit_len(bytes[0] >> 3) / 8;
+        if bytes.len() > len {
+            return Err(DekuError::InvalidParam("UF is larger than the message length".into()));
+        }
+        bytes.resize(len, 0);
+
+        bytes[len - 3..].fill(0);
+        let parity = crc::modes_checksum(&bytes, len * 8)? ^ self.crc;
+        bytes[len - 3..].copy_from_slice(&parity.to_be_bytes()[1..]);
+
+        Ok(bytes)
+    }
+
+    /// Address of the interrogated aircraft, `ffffff` for all-calls
+    ///
+    /// Unlike the replies, the address is encoded with the parity polynomial before it's
+    /// overlaid on the AP field, and is decoded back from [`Self::crc`].
+    ///
+    /// ```rust
+    /// use adsb_deku::uplink::{Uplink, UF};
+    /// use adsb_deku::ICAO;
+    ///
+    /// let uf = UF::ShortAirAirSurveillance { rl: false, aq: true, ds: 0, ap: ICAO([0; 3]) };
+    /// let bytes = Uplink::new(uf, ICAO([0xa3, 0xec, 0xce])).to_bytes().unwrap();
+    /// let uplink = Uplink::from_bytes(&bytes).unwrap();
+    /// assert_eq!(uplink.address().to_string(), "a3ecce");
+    /// ```
+    #[must_use]
+    pub fn address(&self) -> ICAO {
+        let bytes = crc::uplink_address(self.crc).to_be_bytes();
+        ICAO([bytes[1], bytes[2], bytes[3]])
+    }
+
+    /// Length of message in bits, from the UF
+    fn bit_len(uf: u8) -> usize {
+        if uf & 0x10 != 0 {
+            112
+        } else {
+            56
+        }
+    }
+}
+
+impl fmt::Display for Uplink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let address = self.address();
+        match &self.uf {
+            UF::ShortAirAirSurveillance { rl, aq, ds, .. } => {
+                writeln!(f, " Short Air-Air Surveillance Interrogation")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                writeln!(f, "  Reply length:  {}", if *rl { "long" } else { "short" })?;
+                writeln!(f, "  Acquisition:   {aq}")?;
+                writeln!(f, "  DS:            {ds:02x}")?;
+            }
+            UF::SurveillanceAltitudeRequest { pc, rr, sd, .. } => {
+                writeln!(f, " Surveillance, Altitude Request")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                write!(f, "{}", Request { pc, rr: *rr, sd })?;
+            }
+            UF::SurveillanceIdentityRequest { pc, rr, sd, .. } => {
+                writeln!(f, " Surveillance, Identity Request")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                write!(f, "{}", Request { pc, rr: *rr, sd })?;
+            }
+            UF::AllCall { pr, ic, cl, .. } => {
+                writeln!(f, " Mode S Only All-Call")?;
+                writeln!(f, "  Address:       {address}")?;
+                writeln!(f, "  PR:            {pr}")?;
+                match cl.interrogator_code(*ic) {
+                    Some(code) => writeln!(f, "  Interrogator:  {code}")?,
+                    None => writeln!(f, "  Interrogator:  unknown (CL {cl:?}, IC {ic})")?,
+                }
+            }
+            UF::LongAirAirSurveillance { rl, aq, mu, .. } => {
+                writeln!(f, " Long Air-Air Surveillance Interrogation")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                writeln!(f, "  Reply length:  {}", if *rl { "long" } else { "short" })?;
+                writeln!(f, "  Acquisition:   {aq}")?;
+                writeln!(f, "  MU:            {}", Hex(mu))?;
+            }
+            UF::CommAAltitudeRequest { pc, rr, sd, ma, .. } => {
+                writeln!(f, " Comm-A, Altitude Request")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                write!(f, "{}", Request { pc, rr: *rr, sd })?;
+                writeln!(f, "  MA:            {}", Hex(ma))?;
+            }
+            UF::CommAIdentityRequest { pc, rr, sd, ma, .. } => {
+                writeln!(f, " Comm-A, Identity Request")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                write!(f, "{}", Request { pc, rr: *rr, sd })?;
+                writeln!(f, "  MA:            {}", Hex(ma))?;
+            }
+            UF::CommCExtendedLengthMessage { segment, .. } => {
+                writeln!(f, " Comm-C Extended Length Message")?;
+                writeln!(f, "  ICAO Address:  {address} (Mode S)")?;
+                writeln!(f, "  RC:            {}", segment.rc)?;
+                writeln!(f, "  Segment:       {}", segment.nc)?;
+                writeln!(f, "  MC:            {}", Hex(&segment.mc))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Uplink Format (3.1.2.3.2.1.1)
+///
+/// Starting with 5 bits, decode the rest of the message as the correct data packets
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "5")]
+pub enum UF {
+    /// 0: Short Air-Air Surveillance (ACAS), answered by DF0 or DF16 (3.1.2.8.1)
+    #[deku(id = "0")]
+    ShortAirAirSurveillance {
+        /// RL: Reply Length, DF16 if set
+        #[deku(pad_bits_before = "3", bits = "1")]
+        rl: bool,
+        /// AQ: Acquisition
+        #[deku(pad_bits_before = "4", bits = "1")]
+        aq: bool,
+        /// DS: Data Selector, BDS of the register requested in the DF16 MV field
+        #[deku(pad_bits_after = "10")]
+        ds: u8,
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 4: Surveillance, Altitude Request, answered by DF4 or DF20 (3.1.2.6.2)
+    #[deku(id = "4")]
+    SurveillanceAltitudeRequest {
+        /// PC: Protocol
+        pc: ProtocolControl,
+        /// RR: Reply Request, see [`reply_request`]
+        #[deku(bits = "5")]
+        rr: u8,
+        /// DI: Designator Identification and SD: Special Designator
+        sd: SpecialDesignator,
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 5: Surveillance, Identity Request, answered by DF5 or DF21 (3.1.2.6.4)
+    #[deku(id = "5")]
+    SurveillanceIdentityRequest {
+        /// PC: Protocol
+        pc: ProtocolControl,
+        /// RR: Reply Request, see [`reply_request`]
+        #[deku(bits = "5")]
+        rr: u8,
+        /// DI: Designator Identification and SD: Special Designator
+        sd: SpecialDesignator,
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 11: Mode S Only All-Call, answered by DF11 (3.1.2.5.2.1)
+    #[deku(id = "11")]
+    AllCall {
+        /// PR: Probability of Reply
+        pr: ProbabilityOfReply,
+        /// IC: Interrogator Code, see [`CodeLabel::interrogator_code`]
+        #[deku(bits = "4")]
+        ic: u8,
+        /// CL: Code Label
+        #[deku(pad_bits_after = "16")]
+        cl: CodeLabel,
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 16: Long Air-Air Surveillance (ACAS), answered by DF16 (3.1.2.8.3)
+    #[deku(id = "16")]
+    LongAirAirSurveillance {
+        /// RL: Reply Length
+        #[deku(pad_bits_before = "3", bits = "1")]
+        rl: bool,
+        /// AQ: Acquisition
+        #[deku(pad_bits_before = "4", bits = "1")]
+        aq: bool,
+        /// MU: Message, ACAS
+        #[deku(pad_bits_before = "18")]
+        mu: [u8; 7],
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 20: Comm-A, Altitude Request, answered by DF4 or DF20 (3.1.2.6.2)
+    #[deku(id = "20")]
+    CommAAltitudeRequest {
+        /// PC: Protocol
+        pc: ProtocolControl,
+        /// RR: Reply Request, see [`reply_request`]
+        #[deku(bits = "5")]
+        rr: u8,
+        /// DI: Designator Identification and SD: Special Designator
+        sd: SpecialDesignator,
+        /// MA: Message, Comm-A
+        ma: [u8; 7],
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 21: Comm-A, Identity Request, answered by DF5 or DF21 (3.1.2.6.4)
+    #[deku(id = "21")]
+    CommAIdentityRequest {
+        /// PC: Protocol
+        pc: ProtocolControl,
+        /// RR: Reply Request, see [`reply_request`]
+        #[deku(bits = "5")]
+        rr: u8,
+        /// DI: Designator Identification and SD: Special Designator
+        sd: SpecialDesignator,
+        /// MA: Message, Comm-A
+        ma: [u8; 7],
+        /// AP: Address/Parity
+        ap: ICAO,
+    },
+
+    /// 24..=31: Comm-C Extended Length Message segment (3.1.2.7.1)
+    #[deku(id_pat = "24..=31")]
+    CommCExtendedLengthMessage {
+        /// "11", RC and the first bit of NC
+        id: u8,
+        /// RC, NC, MC and AP
+        #[deku(ctx = "*id")]
+        segment: CommC,
+    },
+}
+
+/// PC: Protocol of surveillance and Comm-A interrogations
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum ProtocolControl {
+    #[deku(id = "0")]
+    NoChanges,
+
+    /// Lockout to Mode A/C/S all-calls
+    #[deku(id = "1")]
+    NonSelectiveLockout,
+
+    /// Cancel Comm-B after the reply is closed out
+    #[deku(id = "4")]
+    CancelB,
+
+    /// Cancel the Comm-C ELM
+    #[deku(id = "5")]
+    CancelC,
+
+    /// Cancel the Comm-D ELM
+    #[deku(id = "6")]
+    CancelD,
+
+    #[deku(id_pat = "_")]
+    Unassigned(u8),
+}
+
+impl fmt::Display for ProtocolControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoChanges => write!(f, "no changes"),
+            Self::NonSelectiveLockout => write!(f, "non-selective all-call lockout"),
+            Self::CancelB => write!(f, "cancel Comm-B"),
+            Self::CancelC => write!(f, "cancel Comm-C"),
+            Self::CancelD => write!(f, "cancel Comm-D"),
+            Self::Unassigned(pc) => write!(f, "unassigned ({pc})"),
+        }
+    }
+}
+
+/// Content of the reply to an RR: Reply Request
+///
+/// Below 16 a surveillance reply (DF4/DF5) is requested, otherwise a Comm-B reply (DF20/DF21)
+/// with the register of the returned BDS1 and, for DI=3 and DI=7, the BDS2 from the RRS of
+/// [`SpecialDesignator::SurveillanceIdentifier`] or [`SpecialDesignator::ReplyRequest`]. `None`
+/// for a surveillance reply.
+#[must_use]
+pub fn reply_request(rr: u8, sd: &SpecialDesignator) -> Option<(u8, u8)> {
+    let bds1 = rr.checked_sub(16)?;
+    let bds2 = match sd {
+        SpecialDesignator::SurveillanceIdentifier { rrs, .. }
+        | SpecialDesignator::ReplyRequest { rrs, .. } => *rrs,
+        _ => 0,
+    };
+    Some((bds1, bds2))
+}
+
+/// DI: Designator Identification, with the SD: Special Designator it selects
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum SpecialDesignator {
+    /// DI=0
+    #[deku(id = "0")]
+    Interrogator {
+        /// IIS: Interrogator Identifier Subfield
+        #[deku(bits = "4")]
+        iis: u8,
+        /// OVC: Overlay Control
+        #[deku(pad_bits_before = "7", bits = "1")]
+        ovc: bool,
+        /// TMS: Tactical Message Subfield
+        #[deku(bits = "4")]
+        tms: u8,
+    },
+
+    /// DI=1, multisite reservations
+    #[deku(id = "1")]
+    Multisite {
+        /// IIS: Interrogator Identifier Subfield
+        #[deku(bits = "4")]
+        iis: u8,
+        /// MBS: Multisite Comm-B Subfield
+        #[deku(bits = "2")]
+        mbs: u8,
+        /// MES: Multisite ELM Subfield
+        #[deku(bits = "3")]
+        mes: u8,
+        /// LOS: Lockout Subfield
+        #[deku(bits = "1")]
+        los: bool,
+        /// RSS: Reservation Status Subfield
+        #[deku(bits = "2")]
+        rss: u8,
+        /// TMS: Tactical Message Subfield
+        #[deku(bits = "4")]
+        tms: u8,
+    },
+
+    /// DI=2, extended squitter control
+    #[deku(id = "2")]
+    ExtendedSquitter {
+        /// TCS: Type Control Subfield
+        #[deku(pad_bits_before = "4", bits = "3")]
+        tcs: u8,
+        /// RCS: Rate Control Subfield
+        #[deku(bits = "3")]
+        rcs: u8,
+        /// SAS: Surface Antenna Subfield
+        #[deku(bits = "2", pad_bits_after = "4")]
+        sas: u8,
+    },
+
+    /// DI=3, SI code lockout
+    #[deku(id = "3")]
+    SurveillanceIdentifier {
+        /// SIS: Surveillance Identifier Subfield
+        #[deku(bits = "6")]
+        sis: u8,
+        /// LSS: Lockout Surveillance Subfield
+        #[deku(bits = "1")]
+        lss: bool,
+        /// RRS: Reply Request Subfield, BDS2 of the requested register
+        #[deku(bits = "4")]
+        rrs: u8,
+        /// OVC: Overlay Control
+        #[deku(bits = "1")]
+        ovc: bool,
+        /// TMS: Tactical Message Subfield
+        #[deku(bits = "4")]
+        tms: u8,
+    },
+
+    /// DI=7, extended data readout
+    #[deku(id = "7")]
+    ReplyRequest {
+        /// IIS: Interrogator Identifier Subfield
+        #[deku(bits = "4")]
+        iis: u8,
+        /// RRS: Reply Request Subfield, BDS2 of the requested register
+        #[deku(bits = "4")]
+        rrs: u8,
+        /// LOS: Lockout Subfield
+        #[deku(pad_bits_before = "1", bits = "1")]
+        los: bool,
+        /// OVC: Overlay Control
+        #[deku(pad_bits_before = "1", bits = "1")]
+        ovc: bool,
+        /// TMS: Tactical Message Subfield
+        #[deku(bits = "4")]
+        tms: u8,
+    },
+
+    /// DI=4..=6
+    #[deku(id_pat = "_")]
+    Unassigned {
+        di: u8,
+        #[deku(endian = "big")]
+        sd: u16,
+    },
+}
+
+impl fmt::Display for SpecialDesignator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Interrogator { iis, ovc, tms } => {
+                writeln!(f, "  DI:            0")?;
+                writeln!(f, "  IIS:           {iis}")?;
+                writeln!(f, "  OVC:           {ovc}")?;
+                writeln!(f, "  TMS:           {tms}")?;
+            }
+            Self::Multisite { iis, mbs, mes, los, rss, tms } => {
+                writeln!(f, "  DI:            1")?;
+                writeln!(f, "  IIS:           {iis}")?;
+                writeln!(f, "  MBS:           {mbs}")?;
+                writeln!(f, "  MES:           {mes}")?;
+                writeln!(f, "  LOS:           {los}")?;
+                writeln!(f, "  RSS:           {rss}")?;
+                writeln!(f, "  TMS:           {tms}")?;
+            }
+            Self::ExtendedSquitter { tcs, rcs, sas } => {
+                writeln!(f, "  DI:            2")?;
+                writeln!(f, "  TCS:           {tcs}")?;
+                writeln!(f, "  RCS:           {rcs}")?;
+                writeln!(f, "  SAS:           {sas}")?;
+            }
+            Self::SurveillanceIdentifier { sis, lss, rrs, ovc, tms } => {
+                writeln!(f, "  DI:            3")?;
+                writeln!(f, "  SIS:           {sis}")?;
+                writeln!(f, "  LSS:           {lss}")?;
+                writeln!(f, "  RRS:           {rrs}")?;
+                writeln!(f, "  OVC:           {ovc}")?;
+                writeln!(f, "  TMS:           {tms}")?;
+            }
+            Self::ReplyRequest { iis, rrs, los, ovc, tms } => {
+                writeln!(f, "  DI:            7")?;
+                writeln!(f, "  IIS:           {iis}")?;
+                writeln!(f, "  RRS:           {rrs}")?;
+                writeln!(f, "  LOS:           {los}")?;
+                writeln!(f, "  OVC:           {ovc}")?;
+                writeln!(f, "  TMS:           {tms}")?;
+            }
+            Self::Unassigned { di, sd } => {
+                writeln!(f, "  DI:            {di}")?;
+                writeln!(f, "  SD:            {sd:04x}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// PC, RR, DI and SD of surveillance and Comm-A interrogations
+struct Request<'a> {
+    pc: &'a ProtocolControl,
+    rr: u8,
+    sd: &'a SpecialDesignator,
+}
+
+impl fmt::Display for Request<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  PC:            {}", self.pc)?;
+        match reply_request(self.rr, self.sd) {
+            Some((bds1, bds2)) => writeln!(f, "  Reply request: Comm-B, BDS {bds1},{bds2}")?,
+            None => writeln!(f, "  Reply request: surveillance")?,
+        }
+        write!(f, "{}", self.sd)
+    }
+}
+
+/// Bytes of a message field, as hex
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// PR: Probability of Reply of [`UF::AllCall`]
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbabilityOfReply {
+    /// Reply even if locked out
+    #[deku(bits = "1")]
+    pub disregard_lockout: bool,
+    #[deku(bits = "3")]
+    pub code: u8,
+}
+
+impl ProbabilityOfReply {
+    /// Probability of replying to this all-call, `None` if unassigned
+    #[must_use]
+    pub fn probability(&self) -> Option<f32> {
+        match self.code {
+            0 => Some(1.0),
+            1 => Some(0.5),
+            2 => Some(0.25),
+            3 => Some(0.125),
+            4 => Some(0.0625),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ProbabilityOfReply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.probability() {
+            Some(probability) => write!(f, "{probability}")?,
+            None => write!(f, "unassigned ({})", self.code)?,
+        }
+        if self.disregard_lockout {
+            write!(f, ", disregard lockout")?;
+        }
+        Ok(())
+    }
+}
+
+/// CL: Code Label of [`UF::AllCall`], how to read its IC
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(id_type = "u8", bits = "3")]
+pub enum CodeLabel {
+    #[deku(id = "0")]
+    II,
+    #[deku(id = "1")]
+    SI1To15,
+    #[deku(id = "2")]
+    SI16To31,
+    #[deku(id = "3")]
+    SI32To47,
+    #[deku(id = "4")]
+    SI48To63,
+    #[deku(id_pat = "_")]
+    Unassigned(u8),
+}
+
+impl CodeLabel {
+    /// Interrogator code of the IC field, `None` if the code label is unassigned
+    #[must_use]
+    pub fn interrogator_code(&self, ic: u8) -> Option<InterrogatorCode> {
+        Some(match self {
+            Self::II => InterrogatorCode::II(ic),
+            Self::SI1To15 => InterrogatorCode::SI(ic),
+            Self::SI16To31 => InterrogatorCode::SI(16 + ic),
+            Self::SI32To47 => InterrogatorCode::SI(32 + ic),
+            Self::SI48To63 => InterrogatorCode::SI(48 + ic),
+            Self::Unassigned(_) => return None,
+        })
+    }
+}
+
+/// Interrogator code, also replied in the PI field of DF11
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InterrogatorCode {
+    /// Interrogator Identifier, 0..=15
+    II(u8),
+    /// Surveillance Identifier, 1..=63
+    SI(u8),
+}
+
+impl fmt::Display for InterrogatorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::II(ii) => write!(f, "II {ii}"),
+            Self::SI(si) => write!(f, "SI {si}"),
+        }
+    }
+}
+
+/// RC: Reply Control of a Comm-C segment
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplyControl {
+    InitialSegment = 0,
+    IntermediateSegment = 1,
+    FinalSegment = 2,
+    /// Request for the downlink ELM, with the segments in [`CommC::requested_segments`]
+    DownlinkRequest = 3,
+}
+
+impl fmt::Display for ReplyControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InitialSegment => "initial segment",
+                Self::IntermediateSegment => "intermediate segment",
+                Self::FinalSegment => "final segment",
+                Self::DownlinkRequest => "downlink ELM request",
+            }
+        )
+    }
+}
+
+/// Fields of [`UF::CommCExtendedLengthMessage`] after the first five bits
+///
+/// As for [`crate::comm_d::CommD`], the UF field holds RC and the first bit of NC.
+#[derive(Debug, PartialEq, Eq, DekuRead, DekuWrite, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[deku(ctx = "id: u8")]
+pub struct CommC {
+    /// RC: Reply Control
+    #[deku(reader = "Self::read_rc(id)", writer = "Self::write_rc(*rc, id)")]
+    pub rc: ReplyControl,
+    /// NC: Number of C-segment, 0..=15
+    #[deku(
+        reader = "Self::read_nc(deku::reader, id)",
+        writer = "Self::write_nc(deku::writer, *nc, id)"
+    )]
+    pub nc: u8,
+    /// MC: Message, Comm-C
+    pub mc: [u8; 10],
+    /// AP: Address/Parity
+    pub parity: ICAO,
+}
+
+impl CommC {
+    /// SRS: Segment Request Subfield of a downlink ELM request, bit `n` from the left set to
+    /// request the segment with ND `n`
+    #[must_use]
+    pub fn requested_segments(&self) -> Option<u16> {
+        (self.rc == ReplyControl::DownlinkRequest)
+            .then(|| u16::from_be_bytes([self.mc[0], self.mc[1]]))
+    }
+
+    fn read_rc(id: u8) -> Result<ReplyControl, DekuError> {
+        Ok(match (id >> 1) & 0b11 {
+            0 => ReplyControl::InitialSegment,
+            1 => ReplyControl::IntermediateSegment,
+            2 => ReplyControl::FinalSegment,
+            _ => ReplyControl::DownlinkRequest,
+        })
+    }
+
+    /// Already written as part of the UF, only check that they match
+    fn write_rc(rc: ReplyControl, id: u8) -> Result<(), DekuError> {
+        if rc as u8 == (id >> 1) & 0b11 {
+            Ok(())
+        } else {
+            Err(DekuError::InvalidParam("RC does not match UF".into()))
+        }
+    }
+
+    fn read_nc<R: Read + Seek>(reader: &mut Reader<R>, id: u8) -> Result<u8, DekuError> {
+        let rest = u8::from_reader_with_ctx(reader, BitSize(3))?;
+        Ok(((id & 1) << 3) | rest)
+    }
+
+    /// Only the last 3 bits, the first is written as part of the UF
+    fn write_nc<W: Write + Seek>(writer: &mut Writer<W>, nc: u8, id: u8) -> Result<(), DekuError> {
+        if nc >> 3 != id & 1 {
+            return Err(DekuError::InvalidParam("NC does not match UF".into()));
+        }
+        (nc & 0b111).to_writer(writer, BitSize(3))
+    }
+}
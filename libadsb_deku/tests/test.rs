@@ -1,6 +1,25 @@
-use adsb_deku::adsb::{VerticalRateSource, ME};
-use adsb_deku::{CPRFormat, Capability, Frame, DF};
+use adsb_deku::acas::{Advisory, ThreatIdentity, MV};
+use adsb_deku::adsb::{
+    ADSBVersion, AirborneVelocitySubType, AirspeedType, ControlField, ControlFieldType,
+    EmitterCategory, GpsAntennaOffset, HeadingReference, HorizontalVelocity, Identification,
+    Integrity, MilitaryApplication, OperationStatus, OperationalCoordination, TargetStateAndStatus,
+    TestMessage, TrackAngleHeading, TypeCoding, VerticalRateSource, ADSB, ME,
+};
+use adsb_deku::bds::{
+    infer, infer_with_context, Candidate, Context, SelectedVerticalIntention, TargetAltitudeSource,
+    TrackAndTurnReport, BDS,
+};
+use adsb_deku::comm_d::{CommD, ElmReassembler};
+use adsb_deku::cpr::{self, Position};
+use adsb_deku::uplink::{
+    reply_request, CodeLabel, CommC, InterrogatorCode, ProbabilityOfReply, ProtocolControl,
+    ReplyControl, SpecialDesignator, Uplink, UF,
+};
+use adsb_deku::{
+    AC13Field, AddressSource, CPRFormat, Callsign, Capability, Error, Frame, DF, ICAO, KE,
+};
 use assert_hex::assert_eq_hex;
+use core::time::Duration;
 use hexlit::hex;
 use test_log::test;
 
@@ -60,8 +79,7 @@ fn testing03() {
     let bytes = hex!("8da08f94ea1b785e8f3c088ab467");
     let frame = Frame::from_bytes(&bytes);
     if let DF::ADSB(adsb) = frame.unwrap().df {
-        if let ME::TargetStateAndStatusInformation(me) = adsb.me {
-            assert_eq!(me.subtype, 1);
+        if let ME::TargetStateAndStatusInformation(TargetStateAndStatus::Version2(me)) = adsb.me {
             assert!(!me.is_fms);
             assert_eq!(me.altitude, 14016);
             assert!((me.qnh - 1012.8).abs() < f32::EPSILON);
@@ -153,8 +171,7 @@ fn testing06() {
     let bytes = hex!("8dab3d17ea486860015f4870b796");
     let frame = Frame::from_bytes(&bytes).unwrap();
     if let DF::ADSB(adsb) = frame.df {
-        if let ME::TargetStateAndStatusInformation(me) = adsb.me {
-            assert_eq!(me.subtype, 1);
+        if let ME::TargetStateAndStatusInformation(TargetStateAndStatus::Version2(me)) = adsb.me {
             assert!(!me.is_fms);
             assert_eq!(me.altitude, 37024);
             assert!((me.qnh - 1013.6).abs() < f32::EPSILON);
@@ -457,7 +474,7 @@ fn testing_aircraftidentificationandcategory() {
   Address:       a3f9cb (Mode S / ADS-B)
   Air/Ground:    airborne
   Ident:         N3550U
-  Category:      A1
+  Category:      A1 (light)
 "#,
         resulting_string
     );
@@ -501,6 +518,54 @@ fn testing_issue_03() {
     );
 }
 
+#[test]
+fn test_df16_acas() {
+    let bytes = hex!("80e1969030c2000686cb0c9f9d4c");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::LongAirAir { mv: MV::ResolutionAdvisory(ra), .. } = frame.df else { unreachable!() };
+    assert_eq!(
+        ra.advisory(),
+        Advisory::Single {
+            corrective: true,
+            downward_sense: false,
+            increased_rate: false,
+            sense_reversal: false,
+            altitude_crossing: false,
+            positive: true,
+        }
+    );
+    assert_eq!(ra.threat, ThreatIdentity::Address(ICAO([0xa1, 0xb2, 0xc3])));
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Long Air-Air ACAS
+  ICAO Address:  ac049e (Mode S / ADS-B)
+  Air/Ground:    airborne?
+  Baro altitude: 35000 ft
+  ACAS RA:       corrective climb
+  Threat:        a1b2c3
+"#,
+        resulting_string
+    );
+
+    let bytes = hex!("80e19690300002688206907e915a");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Long Air-Air ACAS
+  ICAO Address:  ac049e (Mode S / ADS-B)
+  Air/Ground:    airborne?
+  Baro altitude: 35000 ft
+  ACAS RA:       none
+  RAC:           not-below not-right
+  RA terminated
+  Threat alt:    5400 ft
+  Threat range:  2.5 NM
+  Threat brg:    90 deg
+"#,
+        resulting_string
+    );
+}
+
 #[test]
 fn testing_issue_04() {
     let bytes = hex!("0621776e99b6ad");
@@ -510,11 +575,63 @@ fn testing_issue_04() {
         r#" Short Air-Air Surveillance
   ICAO Address:  a33325 (Mode S / ADS-B)
   Air/Ground:    ground
+  Altitude:      2990 m barometric
 "#,
         resulting_string
     );
 }
 
+#[test]
+fn test_ac13_field() {
+    // 25 ft, 100 ft gillham coded and metric altitudes
+    let bytes = hex!("02e19cb02512c3");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ShortAirAirSurveillance { altitude, .. } = frame.df else { unreachable!() };
+    assert_eq!(altitude, AC13Field::Feet25(45000));
+
+    let bytes = hex!("020007a0d08ff4");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ShortAirAirSurveillance { altitude, .. } = frame.df else { unreachable!() };
+    assert_eq!(altitude, AC13Field::Feet100(10600));
+
+    let bytes = hex!("0621776e99b6ad");
+    let mut frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ShortAirAirSurveillance { altitude, .. } = frame.df else { unreachable!() };
+    assert_eq!(altitude, AC13Field::Meters(2990));
+    assert_eq!(altitude.feet(), Some(9810));
+
+    // below sea level, unavailable and invalid altitudes are not mistaken for each other
+    for expected in [
+        AC13Field::Feet25(-475),
+        AC13Field::Feet25(0),
+        AC13Field::Feet100(-1200),
+        AC13Field::Feet100(-100),
+        AC13Field::Feet100(126_700),
+        AC13Field::Meters(4094),
+        AC13Field::Unavailable,
+        AC13Field::Invalid(0b1_1111_1111_1111),
+    ] {
+        if let DF::ShortAirAirSurveillance { altitude, .. } = &mut frame.df {
+            *altitude = expected;
+        }
+        let frame = Frame::from_bytes(&frame.to_bytes().unwrap()).unwrap();
+        let DF::ShortAirAirSurveillance { altitude, .. } = frame.df else { unreachable!() };
+        assert_eq!(altitude, expected);
+    }
+
+    // out of range altitudes are rejected instead of truncated
+    for rejected in
+        [AC13Field::Feet100(-1300), AC13Field::Feet100(126_800), AC13Field::Meters(4096)]
+    {
+        if let DF::ShortAirAirSurveillance { altitude, .. } = &mut frame.df {
+            *altitude = rejected;
+        }
+        assert!(frame.to_bytes().is_err());
+    }
+    assert_eq!(AC13Field::Feet25(0).feet(), Some(0));
+    assert_eq!(AC13Field::Unavailable.feet(), None);
+}
+
 #[test]
 fn testing_df_21() {
     let bytes = hex!("AE24238D15EE315463718B1AF755");
@@ -536,8 +653,11 @@ fn testing_df_27() {
     let frame = Frame::from_bytes(&bytes).unwrap();
     let resulting_string = format!("{frame}");
     assert_eq!(
-        r#" Mode S Extended Squitter Message
-    ICAO Address:     a01f73 (Mode S / ADS-B)
+        r#" Comm-D Extended Length Message
+  ICAO Address:  a01f73 (Mode S / ADS-B)
+  KE:            uplink ELM acknowledgement
+  Segment:       10
+  MD:            ca7f82613c2db14a49c5
 "#,
         resulting_string
     );
@@ -571,7 +691,7 @@ fn testing_df_18() {
   Address:       a082fb (ADS-R)
   Air/Ground:    airborne?
   Ident:         N132DS
-  Category:      A1
+  Category:      A1 (light)
 "#,
         resulting_string
     );
@@ -633,6 +753,7 @@ fn testing_df_18() {
         r#" Extended Squitter (Non-Transponder) Reserved for surface system status
   Address:       adf9ce (ADS-B)
   Air/Ground:    airborne?
+  Subtype:       1
 "#,
         resulting_string
     );
@@ -643,6 +764,11 @@ fn testing_df_18() {
     assert_eq!(
         r#" Extended Squitter (Non-Transponder) Surface position
   Address:       caeef7 (unknown addressing scheme)
+  Track:         137.8125 deg
+  CPR type:      Surface
+  CPR odd flag:  even
+  CPR latitude:  (106719)
+  CPR longitude: (88287)
 "#,
         resulting_string
     );
@@ -661,15 +787,125 @@ fn testing_df_18() {
    NIC-C:              0
    NACv:               1
    Capability classes: L/W=1
+   Length/width:       < 15 m / < 23 m
    Operational modes:  SAF SDA=2
    NACp:               9
    SIL:                3 (per hour)
-   NICbaro:            0
+   Track/heading:      track angle
    Heading reference:  true north
 "#,
         resulting_string
     );
 }
+#[test]
+fn test_operation_status() {
+    let status = |bytes: &[u8]| {
+        let frame = Frame::from_bytes(bytes).unwrap();
+        let (DF::ADSB(ADSB { me: ME::AircraftOperationStatus(status), .. })
+        | DF::TisB { cf: ControlField { me: ME::AircraftOperationStatus(status), .. }, .. }) =
+            frame.df
+        else {
+            unreachable!();
+        };
+        status
+    };
+
+    let airborne = status(&hex!("8d0d097ef8230007005ab8547268"));
+    assert_eq!(airborne.version(), Some(ADSBVersion::DOC9871AppendixC));
+    assert_eq!(
+        airborne.integrity(),
+        Some(Integrity {
+            nic_supplement_a: Some(true),
+            nic_supplement_c: None,
+            nacp: Some(10),
+            gva: Some(2),
+            sil: Some(3),
+            sil_per_sample: Some(false),
+            sda: Some(3),
+            nic_baro: Some(true),
+            hrd: Some(HeadingReference::TrueNorth),
+            tah: None,
+        })
+    );
+
+    let OperationStatus::Surface(mut surface) = status(&hex!("96A4D01FF900210600493075E234"))
+    else {
+        unreachable!();
+    };
+    assert_eq!(surface.length_width(), Some((15.0, 23.0)));
+    assert_eq!(surface.gps_antenna_offset.lateral(), None);
+    assert_eq!(surface.gps_antenna_offset.longitudinal(), None);
+    let integrity = OperationStatus::Surface(surface).integrity().unwrap();
+    assert_eq!(integrity.nic_supplement_c, Some(false));
+    assert_eq!(integrity.tah, Some(TrackAngleHeading::TrackAngle));
+    assert_eq!(integrity.gva, None);
+    assert_eq!(integrity.nic_baro, None);
+
+    surface.gps_antenna_offset = GpsAntennaOffset { lateral_code: 0b010, longitudinal_code: 4 };
+    assert_eq!(surface.gps_antenna_offset.lateral(), Some(-4));
+    assert_eq!(surface.gps_antenna_offset.longitudinal(), Some(6));
+    assert_eq!(surface.gps_antenna_offset.to_string(), "4 m left, 6 m aft of nose");
+    surface.gps_antenna_offset = GpsAntennaOffset { lateral_code: 0b100, longitudinal_code: 1 };
+    assert_eq!(surface.gps_antenna_offset.lateral(), Some(0));
+    assert_eq!(surface.gps_antenna_offset.to_string(), "0 m right, applied by sensor");
+
+    // version 1 has neither GVA, SIL supplement nor SDA
+    surface.version_number = ADSBVersion::DOC9871AppendixB;
+    let integrity = OperationStatus::Surface(surface).integrity().unwrap();
+    assert_eq!(integrity.nacp, Some(9));
+    assert_eq!(
+        (integrity.nic_supplement_c, integrity.sda, integrity.sil_per_sample),
+        (None, None, None)
+    );
+    surface.version_number = ADSBVersion::DOC9871AppendixA;
+    assert_eq!(OperationStatus::Surface(surface).integrity(), Some(Integrity::default()));
+
+    assert_eq!(status(&hex!("92479249fcb22e16fbdc3bac5b56")).integrity(), None);
+}
+
+#[test]
+fn test_position_accuracy() {
+    use adsb_deku::accuracy::{epu, position_accuracy, NicSupplements};
+
+    let none = NicSupplements::default();
+    let a = NicSupplements { a: true, ..none };
+    let ab = NicSupplements { a: true, b: true, c: false };
+    let b = NicSupplements { b: true, ..none };
+    let v1 = ADSBVersion::DOC9871AppendixB;
+    let v2 = ADSBVersion::DOC9871AppendixC;
+
+    assert_eq!(position_accuracy(9, none, v2, Some(11)).rc, Some(7.5));
+    assert_eq!(position_accuracy(11, ab, v2, None).rc, Some(75.0));
+    assert_eq!(position_accuracy(11, none, v2, None).rc, Some(0.1 * 1852.0));
+    // NIC-A without NIC-B isn't a valid combination in version 2, but is in version 1
+    assert_eq!(position_accuracy(11, a, v2, None).rc, None);
+    assert_eq!(position_accuracy(11, a, v1, None).rc, Some(75.0));
+    assert_eq!(position_accuracy(13, b, v2, None).rc, Some(0.3 * 1852.0));
+    assert_eq!(position_accuracy(16, none, v2, None).rc, Some(8.0 * 1852.0));
+    assert_eq!(position_accuracy(18, none, v2, None).rc, None);
+
+    // surface, with NIC-C
+    let ac = NicSupplements { a: true, b: false, c: true };
+    assert_eq!(position_accuracy(8, ac, v2, None).rc, Some(0.2 * 1852.0));
+    assert_eq!(position_accuracy(8, ac, v1, None).rc, None);
+    assert_eq!(position_accuracy(8, none, v2, None).rc, None);
+
+    // NUCp
+    let accuracy = position_accuracy(12, none, ADSBVersion::DOC9871AppendixA, Some(11));
+    assert_eq!(accuracy.rc, Some(0.2 * 1852.0));
+    assert_eq!(accuracy.epu, Some(0.1 * 1852.0));
+    // surface TC 8 is NUCp 6
+    let accuracy = position_accuracy(8, none, ADSBVersion::DOC9871AppendixA, None);
+    assert_eq!(accuracy.rc, Some(0.2 * 1852.0));
+    assert_eq!(accuracy.epu, Some(0.1 * 1852.0));
+    let accuracy = position_accuracy(7, none, ADSBVersion::DOC9871AppendixA, None);
+    assert_eq!(accuracy.rc, Some(0.1 * 1852.0));
+
+    assert_eq!(epu(8), Some(0.05 * 1852.0));
+    assert_eq!(epu(0), None);
+    assert_eq!(epu(12), None);
+}
+
 #[test]
 fn test_emergency() {
     let bytes = hex!("8dc06800e1108500000000baa81f");
@@ -686,6 +922,284 @@ fn test_emergency() {
     );
 }
 
+#[test]
+fn test_acas_ra_broadcast() {
+    let bytes = hex!("8d4840d6e2c2000686cb0caf177f");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter ACAS resolution advisory broadcast
+  Address:       4840d6 (Mode S / ADS-B)
+  Air/Ground:    airborne
+  ACAS RA:       corrective climb
+  Threat:        a1b2c3
+"#,
+        resulting_string
+    );
+
+    let bytes = hex!("8d4840d6e20002688206a04f3a01");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter ACAS resolution advisory broadcast
+  Address:       4840d6 (Mode S / ADS-B)
+  Air/Ground:    airborne
+  ACAS RA:       none
+  RAC:           not-below not-right
+  RA terminated
+  Threat alt:    5400 ft
+  Threat range:  2.5 NM
+  Threat brg:    186 deg
+"#,
+        resulting_string
+    );
+}
+
+#[test]
+fn test_target_state_and_status_version_1() {
+    let bytes = hex!("8d4840d6e88cb430ed3808ca49c3");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ADSB(ADSB { me: ME::TargetStateAndStatusInformation(tss), .. }) = frame.df else {
+        unreachable!()
+    };
+    let TargetStateAndStatus::Version1(tss) = tss else { unreachable!() };
+    assert_eq!(tss.altitude, Some(35000));
+    assert_eq!(tss.heading, 270);
+    assert!(tss.is_track);
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter Target state and status (V1)
+  Address:       4840d6 (Mode S / ADS-B)
+  Air/Ground:    airborne
+  Target State and Status:
+    Target altitude:   MCP/FCU, 35000 ft (FL)
+    Vertical mode:     capturing/maintaining
+    Target track:      MCP/FCU, 270
+    Horizontal mode:   capturing/maintaining
+    ACAS:              operational, RA active
+    NACp:              9
+    NICbaro:           1
+    SIL:               2
+    Emergency/priority: no emergency
+"#,
+        resulting_string
+    );
+}
+
+#[test]
+fn test_surface_position() {
+    let bytes = hex!("8c4841753a9a153237aef0f275be");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ADSB(ADSB { me: ME::SurfacePosition { surface, .. }, .. }) = frame.df else {
+        unreachable!()
+    };
+    assert_eq!(surface.ground_speed(), Some(17.0));
+    assert_eq!(surface.track(), Some(92.8125));
+    let reference = Position { latitude: 51.990, longitude: 4.375 };
+    let position = cpr::get_surface_position_local(&surface, reference).unwrap();
+    assert!((position.latitude - 52.320_560_519_978_15).abs() < 1e-9);
+    assert!((position.longitude - 4.735_735_212_053_572).abs() < 1e-9);
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter Surface position
+  Address:       484175 (Mode S / ADS-B)
+  Groundspeed:   17 kt
+  Track:         92.8125 deg
+  CPR type:      Surface
+  CPR odd flag:  odd
+  CPR latitude:  (39195)
+  CPR longitude: (110320)
+"#,
+        resulting_string
+    );
+}
+
+#[test]
+fn test_from_bytes_corrected() {
+    let bytes = hex!("8D40621D58C382D690C8AC2863A7");
+    let flip = |bits: &[usize]| {
+        let mut bytes = bytes;
+        for bit in bits {
+            bytes[bit / 8] ^= 0x80 >> (bit % 8);
+        }
+        bytes
+    };
+
+    // no errors
+    let (frame, flipped) = Frame::from_bytes_corrected(&bytes, 2).unwrap();
+    assert_eq!(frame.to_bytes().unwrap(), bytes);
+    assert!(flipped.is_empty());
+
+    // single and two bit errors, including within the parity
+    for bits in [&[40][..], &[5], &[111], &[50, 90], &[7, 32], &[88, 111]] {
+        let (frame, flipped) = Frame::from_bytes_corrected(&flip(bits), 2).unwrap();
+        assert_eq!(frame.to_bytes().unwrap(), bytes);
+        assert_eq!(flipped, bits);
+    }
+    assert!(Frame::from_bytes_corrected(&flip(&[50, 90]), 1).is_err());
+    assert!(Frame::from_bytes_corrected(&flip(&[40]), 0).is_err());
+
+    // the DF and the announced address are never corrected
+    let (frame, flipped) = Frame::from_bytes_corrected(&flip(&[4]), 2).unwrap();
+    assert!(!matches!(frame.df, DF::ADSB(_)));
+    assert!(flipped.is_empty());
+    assert!(Frame::from_bytes_corrected(&flip(&[20]), 2).is_err());
+    assert!(Frame::from_bytes_corrected(&flip(&[20, 60]), 2).is_err());
+
+    // three bit errors are not mistaken for a correction
+    assert!(Frame::from_bytes_corrected(&flip(&[40, 50, 60]), 2).is_err());
+
+    // DF11, with the interrogator code overlaid on the parity
+    let bytes = hex!("5da58fd4561b39");
+    let mut corrupted = bytes;
+    corrupted[4] ^= 0x01;
+    let (frame, flipped) = Frame::from_bytes_corrected(&corrupted, 2).unwrap();
+    assert_eq!(frame.to_bytes().unwrap(), bytes);
+    assert_eq!(flipped, [39]);
+}
+
+#[test]
+fn test_from_bytes_validated() {
+    let bytes = hex!("8D40621D58C382D690C8AC2863A7");
+    assert!(Frame::from_bytes_validated(&bytes).is_ok());
+
+    let mut corrupted = bytes;
+    corrupted[5] ^= 0x01;
+    assert!(matches!(Frame::from_bytes_validated(&corrupted), Err(Error::BadParity { .. })));
+    assert_eq!(
+        Frame::from_bytes_validated(&bytes[..7]).unwrap_err(),
+        Error::Truncated { needed: 14, got: 7 }
+    );
+    assert_eq!(
+        Frame::from_bytes_validated(&[]).unwrap_err(),
+        Error::Truncated { needed: 7, got: 0 }
+    );
+
+    // DF11 replies to an interrogator other than II=0 are valid, anything above the CL and IC
+    // is bad parity
+    use adsb_deku::uplink::InterrogatorCode;
+    let bytes = hex!("5da58fd4561b39");
+    let mut frame = Frame::from_bytes_validated(&bytes).unwrap();
+    for (crc, expected) in [
+        (0x05, Some(InterrogatorCode::II(5))),
+        (0x1f, Some(InterrogatorCode::SI(15))),
+        (0x25, Some(InterrogatorCode::SI(21))),
+        (0x4a, Some(InterrogatorCode::SI(58))),
+        (0x55, None),
+    ] {
+        frame.crc = crc;
+        let frame = Frame::from_bytes_validated(&frame.to_bytes().unwrap()).unwrap();
+        assert_eq!(frame.crc, crc);
+        assert_eq!(frame.interrogator_code(), expected);
+    }
+    frame.crc = 0x85;
+    assert_eq!(
+        Frame::from_bytes_validated(&frame.to_bytes().unwrap()).unwrap_err(),
+        Error::BadParity { syndrome: 0x85 }
+    );
+    assert_eq!(
+        Frame::from_bytes(&hex!("8D40621D58C382D690C8AC2863A7")).unwrap().interrogator_code(),
+        None
+    );
+
+    assert_eq!(
+        Frame::from_bytes_validated(&hex!("08000000000000")).unwrap_err(),
+        Error::UnsupportedDownlinkFormat(1)
+    );
+    let error = Frame::from_bytes_validated(&hex!("88112a4df8e1b3ee88f1d11537e5")).unwrap_err();
+    assert!(matches!(error, Error::InvalidField { .. }));
+    assert_eq!(
+        error.to_string(),
+        "invalid field: CapabilityClassAirborne.reserved0 field failed assertion: reserved0 == 0"
+    );
+}
+
+#[test]
+fn test_frame_address() {
+    let address = |bytes: &[u8]| {
+        Frame::from_bytes(bytes).unwrap().address().map(|a| (a.icao.to_string(), a.source))
+    };
+    let announced = |icao: &str| Some((icao.to_string(), AddressSource::Announced));
+
+    // DF17 and DF11
+    assert_eq!(address(&hex!("8D40621D58C382D690C8AC2863A7")), announced("40621d"));
+    assert_eq!(address(&hex!("5da58fd4561b39")), announced("a58fd4"));
+
+    // DF0/4/20/21, from the parity
+    for (bytes, icao) in [
+        (&hex!("02e19cb02512c3")[..], "0d097e"),
+        (&hex!("200012b0d96e39"), "a3ecce"),
+        (&hex!("a0001910204d7075d35820c25c0c"), "aa6f80"),
+        (&hex!("AE24238D15EE315463718B1AF755"), "a95fdc"),
+    ] {
+        assert_eq!(address(bytes), Some((icao.to_string(), AddressSource::Parity)));
+    }
+
+    // DF18, depending on the control field
+    assert_eq!(address(&hex!("96A082FB213B1CF2113820D6EDDF")), announced("a082fb"));
+    assert_eq!(
+        address(&hex!("91ADF9CEC11C0524407F11538EE5")),
+        Some(("adf9ce".to_string(), AddressSource::NonIcao))
+    );
+    assert_eq!(address(&hex!("97CAEEF737FB1341BF58DF19118A")), None);
+
+    // TIS-B fine airborne position, with the IMF bit marking a track file address
+    let DF::ADSB(adsb) = Frame::from_bytes(&hex!("8D40621D58C382D690C8AC2863A7")).unwrap().df
+    else {
+        unreachable!()
+    };
+    for imf in [0, 1] {
+        let mut me = adsb.me.clone();
+        if let ME::AirbornePositionBaroAltitude { altitude, .. } = &mut me {
+            altitude.saf_or_imf = imf;
+        }
+        let cf = ControlField { t: ControlFieldType::TISB_FINE, aa: adsb.icao, me };
+        let frame = Frame { df: DF::TisB { cf, pi: ICAO([0; 3]) }, crc: 0 };
+        let bytes = frame.to_bytes().unwrap();
+        let source = if imf == 1 { AddressSource::NonIcao } else { AddressSource::Announced };
+        assert_eq!(address(&bytes), Some(("40621d".to_string(), source)));
+        assert_eq!(
+            adsb_deku::peek(&bytes).unwrap().address,
+            Frame::from_bytes(&bytes).unwrap().address()
+        );
+    }
+}
+
+#[test]
+fn test_peek() {
+    // same address as the full decode
+    for bytes in [
+        &hex!("02e19cb02512c3")[..],
+        &hex!("200012b0d96e39"),
+        &hex!("5da58fd4561b39"),
+        &hex!("80e1969058b5025b9850641d2974"),
+        &hex!("8D40621D58C382D690C8AC2863A7"),
+        &hex!("91ADF9CEC11C0524407F11538EE5"),
+        &hex!("96A082FB213B1CF2113820D6EDDF"),
+        &hex!("97CAEEF737FB1341BF58DF19118A"),
+        &hex!("a0001910204d7075d35820c25c0c"),
+        &hex!("AE24238D15EE315463718B1AF755"),
+    ] {
+        let header = adsb_deku::peek(bytes).unwrap();
+        let frame = Frame::from_bytes(bytes).unwrap();
+        assert_eq!(header.df, bytes[0] >> 3);
+        assert_eq!(header.address, frame.address());
+        assert_eq!(header.len, bytes.len());
+    }
+
+    let header = adsb_deku::peek(&hex!("8d4840d6e2c2000686cb0caf177f")).unwrap();
+    assert_eq!((header.type_code, header.subtype), (Some(28), Some(2)));
+    let header = adsb_deku::peek(&hex!("8d4840d6e88cb430ed3808ca49c3")).unwrap();
+    assert_eq!((header.type_code, header.subtype), (Some(29), Some(0)));
+    let header = adsb_deku::peek(&hex!("8D40621D58C382D690C8AC2863A7")).unwrap();
+    assert_eq!((header.type_code, header.subtype), (Some(11), None));
+    let header = adsb_deku::peek(&hex!("5da58fd4561b39")).unwrap();
+    assert_eq!((header.type_code, header.subtype), (None, None));
+
+    assert_eq!(adsb_deku::peek(&hex!("8D40621D58C382")), None);
+    assert_eq!(adsb_deku::peek(&[]), None);
+}
+
 #[test]
 fn issue_10() {
     let bytes = hex!("8DA35EBC9B000024B00C0004E897");
@@ -703,6 +1217,81 @@ fn issue_10() {
     );
 }
 
+#[test]
+fn test_velocity() {
+    let velocity = |bytes: &[u8]| {
+        let DF::ADSB(ADSB { me: ME::AirborneVelocity(vel), .. }) =
+            Frame::from_bytes(bytes).unwrap().df
+        else {
+            unreachable!();
+        };
+        vel
+    };
+
+    let vel = velocity(&hex!("8da3d42599250129780484712c50"));
+    let v = vel.velocity().unwrap();
+    let Some(HorizontalVelocity::GroundSpeed { speed, track }) = v.horizontal else {
+        unreachable!();
+    };
+    assert!((track - 322.197_2).abs() < f32::EPSILON);
+    assert!((speed - 417.655_360_315_176_6).abs() < f64::EPSILON);
+    assert_eq!(v.vertical_rate, Some(0));
+    assert_eq!(v.vrate_src, VerticalRateSource::GeometricAltitude);
+    assert!(!v.supersonic);
+
+    // same message as supersonic, in units of 4 kt
+    let mut supersonic = vel.clone();
+    supersonic.st = 2;
+    let Some(HorizontalVelocity::GroundSpeed { speed: supersonic_speed, track: supersonic_track }) =
+        supersonic.velocity().unwrap().horizontal
+    else {
+        unreachable!();
+    };
+    assert!((supersonic_speed - speed * 4.0).abs() < 1e-9);
+    assert!((supersonic_track - track).abs() < f32::EPSILON);
+
+    let vel = velocity(&hex!("8DA35EBC9B000024B00C0004E897"));
+    let v = vel.velocity().unwrap();
+    assert_eq!(
+        v.horizontal,
+        Some(HorizontalVelocity::Airspeed {
+            speed: Some(292),
+            airspeed_type: AirspeedType::Indicated,
+            heading: None,
+        })
+    );
+    assert_eq!(v.vertical_rate, Some(128));
+    assert_eq!(vel.calculate(), None);
+
+    let mut supersonic = vel.clone();
+    supersonic.st = 4;
+    if let AirborneVelocitySubType::AirspeedDecoding(airspeed) = &mut supersonic.sub_type {
+        airspeed.status_heading = 1;
+        airspeed.mag_heading = 256;
+        airspeed.airspeed_type = 1;
+    }
+    let v = supersonic.velocity().unwrap();
+    assert_eq!(
+        v.horizontal,
+        Some(HorizontalVelocity::Airspeed {
+            speed: Some(292 * 4),
+            airspeed_type: AirspeedType::True,
+            heading: Some(90.0),
+        })
+    );
+    assert!(v.supersonic);
+
+    if let AirborneVelocitySubType::AirspeedDecoding(airspeed) = &mut supersonic.sub_type {
+        airspeed.airspeed = None;
+    }
+    let Some(HorizontalVelocity::Airspeed { speed, .. }) =
+        supersonic.velocity().unwrap().horizontal
+    else {
+        unreachable!();
+    };
+    assert_eq!(speed, None);
+}
+
 #[test]
 fn issue_11_12() {
     let bytes = hex!("8da90a6e000000000000005cab8b");
@@ -734,9 +1323,10 @@ fn fix_issue_unknown() {
     let frame = Frame::from_bytes(&bytes).unwrap();
     let resulting_string = format!("{frame}");
     assert_eq!(
-        r#" Extended Squitter Unknown
+        r#" Extended Squitter Test message
   Address:       85d792 (Mode S / ADS-B)
   Air/Ground:    airborne
+  Subtype:       6
 "#,
         resulting_string
     );
@@ -860,6 +1450,191 @@ fn test_issue_14() {
     );
 }
 
+#[test]
+fn test_bds_4_0() {
+    let bytes = hex!("A000029C85E42F313000007047D3");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Comm-B, Altitude Reply
+  ICAO Address:  4243d0 (Mode S / ADS-B)
+  Altitude:      3300 ft
+  Comm-B format: BDS4,0 Selected vertical intention
+  MCP altitude:  3008 ft
+  FMS altitude:  3008 ft
+  Baro setting:  1020.0 millibars
+"#,
+        resulting_string
+    );
+
+    let intention = SelectedVerticalIntention {
+        mcp_altitude: Some(36000),
+        fms_altitude: None,
+        barometric_pressure: Some(1013.2),
+        mode_status: true,
+        vnav: true,
+        alt_hold: false,
+        approach: false,
+        target_altitude_source_status: true,
+        target_altitude_source: TargetAltitudeSource::McpFcuSelectedAltitude,
+    };
+    let mut frame = frame;
+    if let DF::CommBAltitudeReply { bds, .. } = &mut frame.df {
+        *bds = BDS::SelectedVerticalIntention(intention.clone());
+    }
+    let bytes = frame.to_bytes().unwrap();
+    let mb: [u8; 7] = bytes[4..11].try_into().unwrap();
+    assert!(SelectedVerticalIntention::is_valid(&mb));
+    assert!(matches!(BDS::from_mb(mb).unwrap(), BDS::Unknown { .. }));
+    let candidates = infer(mb);
+    assert_eq!(candidates[0].bds, BDS::SelectedVerticalIntention(intention));
+    assert_eq!(
+        format!("{}", candidates[0].bds),
+        r#"Comm-B format: BDS4,0 Selected vertical intention
+  MCP altitude:  36000 ft
+  Baro setting:  1013.2 millibars
+  Modes:         vnav
+  Target source: MCP/FCU selected altitude
+"#
+    );
+}
+
+#[test]
+fn test_bds_5_0_6_0() {
+    let bytes = hex!("A000139381951536E024D4CCF6B5");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Comm-B, Altitude Reply
+  ICAO Address:  3c4dd2 (Mode S / ADS-B)
+  Altitude:      30275 ft
+  Comm-B format: BDS5,0 Track and turn report
+  Roll angle:    2.1 deg
+  Track angle:   114.3 deg
+  Speed:         438 kt groundspeed
+  Track rate:    0.125 deg/s
+  TAS:           424 kt
+"#,
+        resulting_string
+    );
+
+    let bytes = hex!("A00004128F39F91A7E27C46ADC21");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Comm-B, Altitude Reply
+  ICAO Address:  48507f (Mode S / ADS-B)
+  Altitude:      5450 ft
+  Comm-B format: BDS6,0 Heading and speed report
+  Heading:       42.7 deg magnetic
+  IAS:           252 kt
+  Mach:          0.420
+  Baro rate:     -1920 ft/min
+  Inertial rate: -1920 ft/min
+"#,
+        resulting_string
+    );
+
+    // out of range roll angle isn't a track and turn report
+    let report = TrackAndTurnReport {
+        roll_angle: Some(60.0),
+        track_angle: None,
+        ground_speed: Some(438),
+        track_angle_rate: None,
+        true_airspeed: None,
+    };
+    let mut frame = frame;
+    if let DF::CommBAltitudeReply { bds, .. } = &mut frame.df {
+        *bds = BDS::TrackAndTurnReport(report);
+    }
+    let bytes = frame.to_bytes().unwrap();
+    let mb: [u8; 7] = bytes[4..11].try_into().unwrap();
+    assert!(!TrackAndTurnReport::is_valid(&mb));
+    assert!(!matches!(BDS::from_mb(mb).unwrap(), BDS::TrackAndTurnReport(_)));
+}
+
+#[test]
+fn test_bds_4_4_4_5() {
+    let mb = hex!("28d9e1ebe3eae0");
+    let candidates = infer(mb);
+    assert_eq!(candidates.len(), 1);
+    let bds = &candidates[0].bds;
+    assert_eq!(
+        format!("{bds}"),
+        r#"Comm-B format: BDS4,4 Meteorological routine air report
+  Source:        GNSS
+  Wind:          54 kt from 168.8 deg
+  Temperature:   -20.25 C
+  Pressure:      250 hPa
+  Turbulence:    light
+  Humidity:      50.0 %
+"#
+    );
+
+    let mb = hex!("c051e180020c80");
+    let candidates = infer(mb);
+    assert_eq!(candidates.len(), 1);
+    let bds = &candidates[0].bds;
+    assert_eq!(
+        format!("{bds}"),
+        r#"Comm-B format: BDS4,5 Meteorological hazard report
+  Turbulence:    moderate
+  Icing:         light
+  Temperature:   -30.50 C
+  Radio height:  1600 ft
+"#
+    );
+
+    // encode both back into a Comm-B reply
+    let mut frame = Frame::from_bytes(&hex!("A000029C85E42F313000007047D3")).unwrap();
+    for mb in [hex!("28d9e1ebe3eae0"), hex!("c051e180020c80")] {
+        if let DF::CommBAltitudeReply { bds, .. } = &mut frame.df {
+            *bds = infer(mb).remove(0).bds;
+        }
+        assert_eq_hex!(frame.to_bytes().unwrap()[4..11], mb);
+    }
+}
+
+#[test]
+fn test_bds_infer() {
+    // empty and identified registers
+    assert_eq!(infer([0; 7]), vec![Candidate { bds: BDS::Empty([0; 6]), distance: None }]);
+    let candidates = infer(hex!("204d7075d35820"));
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].bds, BDS::AircraftIdentification("SWA545".parse().unwrap()));
+    assert!(infer(hex!("15ee315463718b")).is_empty());
+
+    // valid as both (5,0) and (6,0)
+    let mb = hex!("87799114e08410");
+    let candidates = infer(mb);
+    assert_eq!(candidates.len(), 2);
+    assert!(matches!(candidates[0].bds, BDS::TrackAndTurnReport(_)));
+    assert!(matches!(candidates[1].bds, BDS::HeadingAndSpeedReport(_)));
+    // decoding never guesses
+    assert_eq!(BDS::from_mb(mb).unwrap(), BDS::Unknown { id: 0x87, unknown: hex!("799114e08410") });
+
+    // (5,0) ground speed of 166 kt doesn't match
+    let context = Context { ground_speed: Some(260.0), heading: Some(25.0) };
+    let candidates = infer_with_context(mb, &context);
+    assert_eq!(candidates.len(), 1);
+    let BDS::HeadingAndSpeedReport(report) = &candidates[0].bds else { unreachable!() };
+    assert_eq!(report.indicated_airspeed, Some(200));
+    assert!((candidates[0].distance.unwrap() - 4.08).abs() < 0.01);
+
+    // (6,0) magnetic heading of 21 degrees doesn't match
+    let context = Context { ground_speed: Some(170.0), heading: Some(215.0) };
+    let candidates = infer_with_context(mb, &context);
+    assert_eq!(candidates.len(), 1);
+    let BDS::TrackAndTurnReport(report) = &candidates[0].bds else { unreachable!() };
+    assert_eq!(report.ground_speed, Some(166));
+
+    // (5,0) close to the context
+    let context = Context { ground_speed: Some(438.0), heading: Some(114.0) };
+    let candidates = infer_with_context(hex!("81951536E024D4"), &context);
+    assert_eq!(candidates.len(), 1);
+    assert!(candidates[0].distance.unwrap() < 1.0);
+}
+
 #[test]
 fn test_issue_09() {
     let bytes = hex!("a00017b010030a80f60000a0fc1e");
@@ -910,9 +1685,117 @@ fn test_operational_coordination() {
     assert_eq!(
         r#" Extended Squitter (Non-Transponder) Aircraft Operational Coordination
   Address:       43e8ee (ADS-B)
+  Subtype:       7
+"#,
+        resulting_string
+    );
+    assert_eq_hex!(frame.to_bytes().unwrap(), bytes);
+
+    // DO-260 (version 0) paired address
+    let frame = Frame {
+        df: DF::ADSB(ADSB {
+            capability: Capability::AG_AIRBORNE,
+            icao: ICAO(hex!("a3f9cb")),
+            me: ME::AircraftOperationalCoordination(OperationalCoordination::PairedAddress {
+                paired_address: ICAO(hex!("a95fdc")),
+                reserved: [0; 3],
+            }),
+            pi: ICAO([0; 3]),
+        }),
+        crc: 0,
+    };
+    let bytes = frame.to_bytes().unwrap();
+    assert_eq!(bytes[4], 30 << 3);
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter Aircraft Operational Coordination
+  Address:       a3f9cb (Mode S / ADS-B)
+  Paired with:   a95fdc
+"#,
+        resulting_string
+    );
+}
+
+#[test]
+fn test_test_message_squawk() {
+    let frame = Frame {
+        df: DF::ADSB(ADSB {
+            capability: Capability::AG_AIRBORNE,
+            icao: ICAO(hex!("a3f9cb")),
+            me: ME::TestMessage(TestMessage::Squawk { squawk: 0x7700 }),
+            pi: ICAO([0; 3]),
+        }),
+        crc: 0,
+    };
+    let bytes = frame.to_bytes().unwrap();
+    assert_eq!(bytes[4], (23 << 3) | 7);
+
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    assert_eq!(frame.crc, 0);
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter Test message
+  Address:       a3f9cb (Mode S / ADS-B)
+  Air/Ground:    airborne
+  Squawk:        7700
+"#,
+        resulting_string
+    );
+
+    // other subtypes are kept as they are
+    let bytes = hex!("8d85d792beaf5654b710d87357ee");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ADSB(ADSB { me: ME::TestMessage(TestMessage::Raw(6, _)), .. }) = frame.df else {
+        unreachable!();
+    };
+    assert_eq_hex!(frame.to_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn test_df19_military() {
+    let bytes = hex!("8da3f9cb213b3d75c1582080f4d9");
+    let DF::ADSB(adsb) = Frame::from_bytes(&bytes).unwrap().df else {
+        unreachable!();
+    };
+    let frame = Frame {
+        df: DF::ExtendedQuitterMilitaryApplication {
+            af: MilitaryApplication::ExtendedSquitter { icao: adsb.icao, me: adsb.me, pi: adsb.pi },
+        },
+        crc: 0,
+    };
+    let bytes = frame.to_bytes().unwrap();
+    assert_eq_hex!(bytes[..11], hex!("98a3f9cb213b3d75c15820"));
+
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    assert_eq!(frame.crc, 0);
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Extended Squitter Aircraft identification and category
+  Address:       a3f9cb (Military)
+  Air/Ground:    airborne?
+  Ident:         N3550U
+  Category:      A1 (light)
 "#,
         resulting_string
     );
+    let address = frame.address().unwrap();
+    assert_eq!((address.icao, address.source), (ICAO(hex!("a3f9cb")), AddressSource::Announced));
+    let header = adsb_deku::peek(&bytes).unwrap();
+    assert_eq!((header.df, header.address, header.type_code), (19, Some(address), Some(4)));
+
+    // other application fields are kept as they are
+    let mut bytes = bytes;
+    bytes[0] = (19 << 3) | 5;
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    assert!(matches!(
+        frame.df,
+        DF::ExtendedQuitterMilitaryApplication { af: MilitaryApplication::Raw(5, _) }
+    ));
+    assert_eq!(frame.address(), None);
+    assert_eq!(frame.to_string(), " Extended Squitter Military Application\n  AF:            5\n");
+    assert_eq_hex!(frame.to_bytes().unwrap(), bytes);
+    assert_eq!(adsb_deku::peek(&bytes).unwrap().type_code, None);
 }
 
 #[test]
@@ -962,3 +1845,364 @@ fn test_df17_error() {
         resulting_string
     );
 }
+
+#[test]
+fn test_encode_round_trip() {
+    let messages = [
+        hex!("02e19cb02512c3").to_vec(),
+        hex!("200012b0d96e39").to_vec(),
+        hex!("5da58fd4561b39").to_vec(),
+        hex!("80e1969058b5025b9850641d2974").to_vec(),
+        hex!("80e1969030c2000686cb0c9f9d4c").to_vec(),
+        hex!("80e19690300002688206907e915a").to_vec(),
+        hex!("8D40621D58C382D690C8AC2863A7").to_vec(),
+        hex!("8DA35EBC9B000024B00C0004E897").to_vec(),
+        hex!("8d0d097ef8230007005ab8547268").to_vec(),
+        hex!("8da3f9cb213b3d75c1582080f4d9").to_vec(),
+        hex!("8da3f9cb9910100da8148571db11").to_vec(),
+        hex!("8da97753ea2d0858015c003ee5de").to_vec(),
+        hex!("8dc06800e1108500000000baa81f").to_vec(),
+        hex!("8d4840d6e2c2000686cb0caf177f").to_vec(),
+        hex!("8d4840d6e88cb430ed3808ca49c3").to_vec(),
+        hex!("8c4841753a9a153237aef0f275be").to_vec(),
+        hex!("96A082FB213B1CF2113820D6EDDF").to_vec(),
+        hex!("96A4D01FF900210600493075E234").to_vec(),
+        hex!("97CAEEF737FB1341BF58DF19118A").to_vec(),
+        hex!("AE24238D15EE315463718B1AF755").to_vec(),
+        hex!("a0001910204d7075d35820c25c0c").to_vec(),
+        hex!("a000171810030a80f6000012bd7b").to_vec(),
+        hex!("A000029C85E42F313000007047D3").to_vec(),
+        hex!("A000139381951536E024D4CCF6B5").to_vec(),
+        hex!("A00004128F39F91A7E27C46ADC21").to_vec(),
+        hex!("daca7f82613c2db14a49c535a3a2").to_vec(),
+        // TC 0 and TC 25 with every bit after the type code set
+        hex!("8da3f9cb07ffffffffffff000000").to_vec(),
+        hex!("8da3f9cbcfffffffffffff000000").to_vec(),
+        // airspeed unavailable
+        hex!("8da3f9cb9b000000000000000000").to_vec(),
+    ];
+    for bytes in messages {
+        let frame = Frame::from_bytes(&bytes).unwrap();
+        assert_eq_hex!(frame.to_bytes().unwrap(), bytes);
+    }
+}
+
+#[test]
+fn test_encode_lossy_round_trip() {
+    // Gillham coded altitudes, unused bits and invalid codes don't survive decoding, but the
+    // decoded values do
+    let messages = [
+        hex!("020007a0d08ff4").to_vec(),
+        hex!("0621776e99b6ad").to_vec(),
+        hex!("2A00516D492B80").to_vec(),
+        hex!("8dab92a2593e0664204c69d8fe84").to_vec(),
+        hex!("92ef92b301154cb9ab09466702c6").to_vec(),
+    ];
+    for bytes in messages {
+        let frame = Frame::from_bytes(&bytes).unwrap();
+        let encoded = frame.to_bytes().unwrap();
+        assert_eq!(encoded.len(), bytes.len());
+        let new_frame = Frame::from_bytes(&encoded).unwrap();
+        assert_eq!(new_frame.crc, frame.crc);
+        assert_eq!(new_frame.to_string(), frame.to_string());
+        assert_eq_hex!(new_frame.to_bytes().unwrap(), encoded);
+    }
+}
+
+#[test]
+fn test_encode_velocity_out_of_range() {
+    use adsb_deku::adsb::{
+        AirborneVelocitySubFields, AirborneVelocityType, AirspeedDecoding, DirectionEW, DirectionNS,
+    };
+    use deku::prelude::*;
+
+    let encode = |new: Option<u16>| {
+        let mut frame = Frame::from_bytes(&hex!("8da3f9cb9b000000000000000000")).unwrap();
+        let DF::ADSB(ADSB { me: ME::AirborneVelocity(velocity), .. }) = &mut frame.df else {
+            unreachable!();
+        };
+        let AirborneVelocitySubType::AirspeedDecoding(AirspeedDecoding { airspeed, .. }) =
+            &mut velocity.sub_type
+        else {
+            unreachable!();
+        };
+        assert_eq!(*airspeed, None);
+        *airspeed = new;
+        frame.to_bytes()
+    };
+    assert!(encode(Some(1022)).is_ok());
+    assert!(encode(Some(1023)).is_err());
+    assert!(encode(Some(u16::MAX)).is_err());
+
+    let fields = AirborneVelocitySubFields {
+        dew: DirectionEW::WestToEast,
+        vew: u16::MAX,
+        dns: DirectionNS::SouthToNorth,
+        vns: 0,
+    };
+    let mut out = std::io::Cursor::new(vec![]);
+    let mut writer = Writer::new(&mut out);
+    assert!(fields.to_writer(&mut writer, AirborneVelocityType::Subsonic).is_err());
+}
+
+#[test]
+fn test_encode_identification() {
+    let frame = Frame {
+        df: DF::ADSB(ADSB {
+            capability: Capability::AG_AIRBORNE,
+            icao: ICAO(hex!("a3f9cb")),
+            me: ME::AircraftIdentification {
+                id: 4,
+                identification: Identification {
+                    tc: TypeCoding::A,
+                    ca: 1,
+                    cn: "N3550U".parse().unwrap(),
+                },
+            },
+            pi: ICAO([0; 3]),
+        }),
+        crc: 0,
+    };
+    let bytes = frame.to_bytes().unwrap();
+    assert_eq_hex!(bytes, hex!("8da3f9cb213b3d75c1582080f4d9"));
+
+    // type code and TypeCoding must agree
+    let frame = Frame {
+        df: DF::ADSB(ADSB {
+            capability: Capability::AG_AIRBORNE,
+            icao: ICAO(hex!("a3f9cb")),
+            me: ME::AircraftIdentification {
+                id: 3,
+                identification: Identification {
+                    tc: TypeCoding::A,
+                    ca: 1,
+                    cn: "N3550U".parse().unwrap(),
+                },
+            },
+            pi: ICAO([0; 3]),
+        }),
+        crc: 0,
+    };
+    assert!(frame.to_bytes().is_err());
+
+    let callsign: Callsign = "N3550U".parse().unwrap();
+    assert_eq!(callsign.as_str(), "N3550U");
+    assert_eq!(callsign.to_string(), "N3550U");
+    assert!("N3550UABC".parse::<Callsign>().is_err());
+    assert!("n3550u".parse::<Callsign>().is_err());
+}
+
+#[test]
+fn test_emitter_category() {
+    let bytes = hex!("8da3f9cb213b3d75c1582080f4d9");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::ADSB(ADSB { me: ME::AircraftIdentification { identification, .. }, .. }) = frame.df
+    else {
+        unreachable!();
+    };
+    assert_eq!(identification.emitter_category(), EmitterCategory::Light);
+
+    assert_eq!(EmitterCategory::new(TypeCoding::A, 0), EmitterCategory::NoInformation);
+    assert_eq!(EmitterCategory::new(TypeCoding::A, 5), EmitterCategory::Heavy);
+    assert_eq!(EmitterCategory::new(TypeCoding::A, 7), EmitterCategory::Rotorcraft);
+    assert_eq!(EmitterCategory::new(TypeCoding::B, 6), EmitterCategory::UnmannedAerialVehicle);
+    assert_eq!(EmitterCategory::new(TypeCoding::C, 1), EmitterCategory::SurfaceEmergencyVehicle);
+    assert_eq!(EmitterCategory::new(TypeCoding::C, 5), EmitterCategory::LineObstacle);
+    assert_eq!(
+        EmitterCategory::new(TypeCoding::B, 5),
+        EmitterCategory::Reserved { tc: TypeCoding::B, ca: 5 }
+    );
+    assert_eq!(
+        EmitterCategory::new(TypeCoding::D, 1),
+        EmitterCategory::Reserved { tc: TypeCoding::D, ca: 1 }
+    );
+    assert_eq!(EmitterCategory::HighVortexLarge.to_string(), "high vortex large");
+}
+
+#[test]
+fn test_comm_d() {
+    let bytes = hex!("c1bbccddeeff001122334481be97");
+    let frame = Frame::from_bytes(&bytes).unwrap();
+    let DF::CommDExtendedLengthMessage { id, segment } = frame.df else {
+        unreachable!();
+    };
+    assert_eq!(id, 24);
+    assert_eq!(segment.ke, KE::DownlinkELMTx);
+    assert_eq!(segment.nd, 1);
+    assert_eq!(segment.md, hex!("bbccddeeff0011223344"));
+    assert_eq!(frame.address().unwrap().icao, ICAO([0xa3, 0xec, 0xce]));
+    assert_eq!(frame.to_bytes().unwrap(), bytes);
+
+    let resulting_string = format!("{frame}");
+    assert_eq!(
+        r#" Comm-D Extended Length Message
+  ICAO Address:  a3ecce (Mode S / ADS-B)
+  KE:            downlink ELM
+  Segment:       1
+  MD:            bbccddeeff0011223344
+"#,
+        resulting_string
+    );
+
+    // the spare bit, KE and the first bit of ND are part of the 5 bit DF
+    let segment = CommD { ke: KE::UplinkELMAck, nd: 9, md: [0; 10], parity: ICAO([0; 3]) };
+    let df = DF::CommDExtendedLengthMessage { id: 0b11011, segment };
+    let bytes = Frame { df: df.clone(), crc: 0 }.to_bytes().unwrap();
+    assert_eq!(bytes[0], 0b1101_1001);
+    let DF::CommDExtendedLengthMessage { segment, .. } = Frame::from_bytes(&bytes).unwrap().df
+    else {
+        unreachable!();
+    };
+    assert_eq!((segment.ke, segment.nd), (KE::UplinkELMAck, 9));
+
+    let df = DF::CommDExtendedLengthMessage { id: 0b11000, segment };
+    assert!(Frame { df, crc: 0 }.to_bytes().is_err());
+}
+
+#[test]
+fn test_elm_reassembler() {
+    let announce = Frame::from_bytes(&hex!("20880000a72c34")).unwrap();
+    let seg0 = Frame::from_bytes(&hex!("c0112233445566778899aa47e3e0")).unwrap();
+    let seg1 = Frame::from_bytes(&hex!("c1bbccddeeff001122334481be97")).unwrap();
+    let ack = Frame::from_bytes(&hex!("e0112233445566778899aab6549e")).unwrap();
+    let DF::SurveillanceAltitudeReply { dr, .. } = announce.df else {
+        unreachable!();
+    };
+    assert_eq!(dr.elm_segments(), Some(2));
+
+    let mut elm = ElmReassembler::new(Duration::from_secs(5));
+    assert_eq!(elm.push(&seg1, Duration::from_secs(0)), None);
+    assert_eq!(elm.push(&ack, Duration::from_secs(0)), None);
+    assert_eq!(elm.push(&seg0, Duration::from_secs(1)), None);
+    let message = elm.push(&announce, Duration::from_secs(2)).unwrap();
+    assert_eq!(message.icao, ICAO([0xa3, 0xec, 0xce]));
+    assert_eq!(message.md, hex!("112233445566778899aabbccddeeff0011223344"));
+    assert_eq!(message.segments().count(), 2);
+    assert!(elm.is_empty());
+
+    // seg0 timed out before seg1
+    assert_eq!(elm.push(&announce, Duration::from_secs(10)), None);
+    assert_eq!(elm.push(&seg0, Duration::from_secs(11)), None);
+    assert_eq!(elm.push(&seg1, Duration::from_secs(20)), None);
+    assert_eq!(elm.len(), 1);
+    elm.expire(Duration::from_secs(26));
+    assert!(elm.is_empty());
+
+    // dropped by pushes from other addresses, without calling expire
+    assert_eq!(elm.push(&seg0, Duration::from_secs(30)), None);
+    assert_eq!(elm.len(), 1);
+    let other = Frame::from_bytes(&hex!("02e19cb02512c3")).unwrap();
+    assert_eq!(elm.push(&other, Duration::from_secs(36)), None);
+    assert!(elm.is_empty());
+}
+
+#[test]
+fn test_uplink() {
+    let icao = ICAO([0xa3, 0xec, 0xce]);
+    let sd = SpecialDesignator::ReplyRequest { iis: 3, rrs: 0, los: false, ovc: false, tms: 0 };
+    let uf = UF::CommAAltitudeRequest {
+        pc: ProtocolControl::NoChanges,
+        rr: 21,
+        sd,
+        ma: hex!("20000000000000"),
+        ap: ICAO([0; 3]),
+    };
+    let bytes = Uplink::new(uf, icao).to_bytes().unwrap();
+    let uplink = Uplink::from_bytes(&bytes).unwrap();
+    assert_eq!(uplink.address(), icao);
+    assert_eq!(uplink.to_bytes().unwrap(), bytes);
+    let UF::CommAAltitudeRequest { rr, sd, .. } = &uplink.uf else {
+        unreachable!();
+    };
+    assert_eq!(reply_request(*rr, sd), Some((5, 0)));
+    let si = SpecialDesignator::SurveillanceIdentifier {
+        sis: 12,
+        lss: false,
+        rrs: 1,
+        ovc: false,
+        tms: 0,
+    };
+    assert_eq!(reply_request(21, &si), Some((5, 1)));
+    assert_eq!(reply_request(15, &si), None);
+
+    let resulting_string = format!("{uplink}");
+    assert_eq!(
+        r#" Comm-A, Altitude Request
+  ICAO Address:  a3ecce (Mode S)
+  PC:            no changes
+  Reply request: Comm-B, BDS 5,0
+  DI:            7
+  IIS:           3
+  RRS:           0
+  LOS:           false
+  OVC:           false
+  TMS:           0
+  MA:            20000000000000
+"#,
+        resulting_string
+    );
+
+    // PR=1/2 disregarding lockout, II=5
+    let uf = UF::AllCall {
+        pr: ProbabilityOfReply { disregard_lockout: true, code: 1 },
+        ic: 5,
+        cl: CodeLabel::II,
+        ap: ICAO([0; 3]),
+    };
+    let bytes = Uplink::new(uf, ICAO([0xff; 3])).to_bytes().unwrap();
+    assert_eq!(bytes.len(), 7);
+    let uplink = Uplink::from_bytes(&bytes).unwrap();
+    assert_eq!(uplink.address(), ICAO([0xff; 3]));
+    let resulting_string = format!("{uplink}");
+    assert_eq!(
+        r#" Mode S Only All-Call
+  Address:       ffffff
+  PR:            0.5, disregard lockout
+  Interrogator:  II 5
+"#,
+        resulting_string
+    );
+    assert_eq!(CodeLabel::SI32To47.interrogator_code(5), Some(InterrogatorCode::SI(37)));
+    assert_eq!(CodeLabel::Unassigned(6).interrogator_code(5), None);
+
+    // every address survives the overlay
+    for address in [[0, 0, 1], [0x80, 0, 0], [0x12, 0x34, 0x56], [0xff, 0xff, 0xfe]] {
+        let uf = UF::SurveillanceIdentityRequest {
+            pc: ProtocolControl::CancelB,
+            rr: 0,
+            sd: SpecialDesignator::Unassigned { di: 5, sd: 0xbeef },
+            ap: ICAO([0; 3]),
+        };
+        let bytes = Uplink::new(uf.clone(), ICAO(address)).to_bytes().unwrap();
+        let uplink = Uplink::from_bytes(&bytes).unwrap();
+        assert_eq!(uplink.address(), ICAO(address));
+        let UF::SurveillanceIdentityRequest { pc, rr, sd, .. } = uplink.uf else {
+            unreachable!();
+        };
+        assert_eq!(
+            (pc, rr, sd),
+            (ProtocolControl::CancelB, 0, SpecialDesignator::Unassigned { di: 5, sd: 0xbeef })
+        );
+    }
+}
+
+#[test]
+fn test_uplink_comm_c() {
+    // RC=3 requesting segments 0 and 1 of the downlink ELM
+    let segment = CommC {
+        rc: ReplyControl::DownlinkRequest,
+        nc: 0,
+        mc: hex!("c0000000000000000000"),
+        parity: ICAO([0; 3]),
+    };
+    let uf = UF::CommCExtendedLengthMessage { id: 0b11110, segment };
+    let bytes = Uplink::new(uf, ICAO([0xa3, 0xec, 0xce])).to_bytes().unwrap();
+    assert_eq!(bytes[0], 0b1111_0000);
+    let uplink = Uplink::from_bytes(&bytes).unwrap();
+    let UF::CommCExtendedLengthMessage { segment, .. } = uplink.uf else {
+        unreachable!();
+    };
+    assert_eq!(segment.rc, ReplyControl::DownlinkRequest);
+    assert_eq!(segment.requested_segments(), Some(0xc000));
+    assert_eq!(uplink.address(), ICAO([0xa3, 0xec, 0xce]));
+}
@@ -6,7 +6,7 @@
 extern crate alloc;
 
 #[cfg(feature = "alloc")]
-use alloc::{collections::BTreeMap, fmt, string::String, vec, vec::Vec};
+use alloc::{collections::BTreeMap, fmt, string::String, string::ToString, vec, vec::Vec};
 #[cfg(feature = "alloc")]
 use core::{
     clone::Clone, default::Default, fmt::Debug, marker::Copy, prelude::rust_2021::derive,
@@ -15,8 +15,11 @@ use core::{
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
-use adsb_deku::adsb::{AirborneVelocity, Identification, ME};
-use adsb_deku::{cpr, Altitude, CPRFormat, Frame, DF, ICAO};
+use adsb_deku::adsb::{
+    AirborneVelocity, EmitterCategory, HorizontalVelocity, Identification, MilitaryApplication,
+    SurfacePosition, ME,
+};
+use adsb_deku::{bds, cpr, Address, Altitude, CPRFormat, Frame, DF, ICAO};
 use tracing::{debug, info, warn};
 
 // Max absurd distance an aircraft travelled between messages
@@ -111,9 +114,10 @@ impl Airplanes {
 
     /// Update `Airplanes` with new `Frame`
     ///
-    /// Take parsed `Frame` and read the `DF::ADSB` type and act upon the parsed message. This
-    /// updates the field that the `ME` value equates to within [`Self`]. This also adds
-    /// airplanes (`ICAO` and `AirplaneState`) when a new aircraft is detected.
+    /// Take parsed `Frame` and read the `DF::ADSB`, `DF::TisB` or DF19 (AF=0) type and act upon
+    /// the parsed message. This updates the field that the `ME` value equates to within
+    /// [`Self`]. This also adds airplanes (`ICAO` and `AirplaneState`) when a new aircraft is
+    /// detected.
     ///
     /// `lat_long`: (latitude, longitude) of current receiver location
     ///
@@ -121,53 +125,41 @@ impl Airplanes {
     ///
     /// Return true if entry was added into `Airplanes`
     pub fn action(&mut self, frame: Frame, lat_long: (f64, f64), max_rang: f64) -> Added {
-        let mut airplane_added = Added::No;
-        match frame.df {
-            DF::ADSB(ref adsb) => {
-                airplane_added = match &adsb.me {
-                    ME::AircraftIdentification { id: _id, identification } => {
-                        self.add_identification(adsb.icao, identification)
-                    }
-                    ME::AirborneVelocity(vel) => self.add_airborne_velocity(adsb.icao, vel),
-                    ME::AirbornePositionGNSSAltitude { id: _id, altitude }
-                    | ME::AirbornePositionBaroAltitude { id: _id, altitude } => {
-                        self.update_position(adsb.icao, altitude, lat_long, max_rang)
-                    }
-                    _ => Added::No,
-                };
-                let incr_airplane_added = self.incr_messages(adsb.icao);
-                airplane_added =
-                    if incr_airplane_added == Added::Yes || airplane_added == Added::Yes {
-                        Added::Yes
-                    } else {
-                        Added::No
-                    };
+        let me = match &frame.df {
+            DF::ADSB(adsb) => &adsb.me,
+            DF::TisB { cf, .. } => {
+                info!("TISB: {cf:?}");
+                &cf.me
             }
-            DF::TisB { cf, pi } => {
-                info!("TISB: {cf:?}, {pi:?}");
-                airplane_added = match cf.me {
-                    ME::AircraftIdentification { id: _id, identification } => {
-                        self.add_identification(pi, &identification)
-                    }
-                    ME::AirborneVelocity(vel) => self.add_airborne_velocity(pi, &vel),
-                    ME::AirbornePositionGNSSAltitude { id: _id, altitude }
-                    | ME::AirbornePositionBaroAltitude { id: _id, altitude } => {
-                        self.update_position(pi, &altitude, lat_long, max_rang)
-                    }
-                    _ => Added::No,
-                };
-                let incr_airplane_added = self.incr_messages(pi);
-                airplane_added =
-                    if incr_airplane_added == Added::Yes || airplane_added == Added::Yes {
-                        Added::Yes
-                    } else {
-                        Added::No
-                    };
+            DF::ExtendedQuitterMilitaryApplication {
+                af: MilitaryApplication::ExtendedSquitter { me, .. },
+            } => me,
+            _ => return Added::No,
+        };
+        let Some(Address { icao, .. }) = frame.address() else {
+            return Added::No;
+        };
+
+        let airplane_added = match me {
+            ME::AircraftIdentification { id: _id, identification } => {
+                self.add_identification(icao, identification)
             }
-            _ => (),
+            ME::AirborneVelocity(vel) => self.add_airborne_velocity(icao, vel),
+            ME::AirbornePositionGNSSAltitude { id: _id, altitude }
+            | ME::AirbornePositionBaroAltitude { id: _id, altitude } => {
+                self.update_position(icao, altitude, lat_long, max_rang)
+            }
+            ME::SurfacePosition { id: _id, surface } => {
+                self.update_surface_position(icao, surface, lat_long, max_rang)
+            }
+            _ => Added::No,
+        };
+        let incr_airplane_added = self.incr_messages(icao);
+        if incr_airplane_added == Added::Yes || airplane_added == Added::Yes {
+            Added::Yes
+        } else {
+            Added::No
         }
-
-        airplane_added
     }
 
     /// from `ICAO` return details on that airplane
@@ -264,7 +256,8 @@ impl Airplanes {
     /// Return true if entry was added into `Airplanes`
     fn add_identification(&mut self, icao: ICAO, identification: &Identification) -> Added {
         let (state, airplane_added) = self.entry_or_insert(icao);
-        state.callsign = Some(identification.cn.clone());
+        state.callsign = Some(identification.cn.to_string());
+        state.category = Some(identification.emitter_category());
         info!("[{icao}] with identification: {}", identification.cn);
 
         airplane_added
@@ -275,11 +268,21 @@ impl Airplanes {
     /// Return true if entry was added into `Airplanes`
     fn add_airborne_velocity(&mut self, icao: ICAO, vel: &AirborneVelocity) -> Added {
         let (state, airplane_added) = self.entry_or_insert(icao);
-        if let Some((heading, ground_speed, vert_speed)) = vel.calculate() {
-            info!("[{icao}] with airborne velocity: heading: {heading}, speed: {ground_speed}, vertical speed: {vert_speed}");
-            state.heading = Some(heading);
-            state.speed = Some(ground_speed as f32);
-            state.vert_speed = Some(vert_speed);
+        if let Some(velocity) = vel.velocity() {
+            info!("[{icao}] with airborne velocity: {velocity:?}");
+            match velocity.horizontal {
+                Some(HorizontalVelocity::GroundSpeed { speed, track }) => {
+                    state.heading = Some(track);
+                    state.speed = Some(speed as f32);
+                }
+                Some(HorizontalVelocity::Airspeed { heading: Some(heading), .. }) => {
+                    state.magnetic_heading = Some(heading);
+                }
+                _ => (),
+            }
+            if velocity.vertical_rate.is_some() {
+                state.vert_speed = velocity.vertical_rate;
+            }
         }
 
         airplane_added
@@ -330,6 +333,59 @@ impl Airplanes {
 
         airplane_added
     }
+
+    /// update from `ME::SurfacePosition`
+    ///
+    /// The position is decoded from a single message, using the receiver location as the
+    /// reference. Aircraft on the surface are well within the 45 NM this allows.
+    ///
+    /// Return true if entry was added into `Airplanes`
+    fn update_surface_position(
+        &mut self,
+        icao: ICAO,
+        surface: &SurfacePosition,
+        lat_long: (f64, f64),
+        max_range: f64,
+    ) -> Added {
+        let (state, airplane_added) = self.entry_or_insert(icao);
+        info!(
+            "[{icao}] on surface with: cpr lat: {}, cpr long: {}",
+            surface.lat_cpr, surface.lon_cpr
+        );
+        state.on_ground = Some(true);
+        if let Some(ground_speed) = surface.ground_speed() {
+            state.speed = Some(ground_speed as f32);
+        }
+        if let Some(track) = surface.track() {
+            state.heading = Some(track);
+        }
+
+        let reference = cpr::Position { latitude: lat_long.0, longitude: lat_long.1 };
+        if let Some(position) = cpr::get_surface_position_local(surface, reference) {
+            let kilo_distance =
+                AirplaneCoor::haversine_distance(lat_long, (position.latitude, position.longitude));
+            if kilo_distance > max_range {
+                warn!("range: {kilo_distance} -  old: {lat_long:?} new: {position:?}");
+                return airplane_added;
+            }
+            if state.coords.position.is_some_and(|current| current != position) {
+                // update track
+                if let Some(track) = &mut state.track {
+                    track.push(state.coords);
+                } else {
+                    state.track = Some(vec![state.coords]);
+                }
+            }
+            state.coords.position = Some(position);
+            state.coords.kilo_distance = Some(kilo_distance);
+            #[cfg(feature = "std")]
+            {
+                state.coords.last_time = Some(SystemTime::now());
+            }
+        }
+
+        airplane_added
+    }
 }
 
 /// Generated by `Airplanes::aircraft_details()`
@@ -351,16 +407,20 @@ pub struct AirplaneState {
     pub coords: AirplaneCoor,
     pub squawk: Option<u32>,
     pub callsign: Option<String>,
-    /// heading from `adsb::AirborneVelocity::calculate()`
+    /// from `adsb::Identification::emitter_category()`
+    pub category: Option<EmitterCategory>,
+    /// track over ground from `adsb::AirborneVelocity::velocity()` or surface position
     ///
     /// 0 = Straight up
     /// 90 = Right, and so on
     pub heading: Option<f32>,
-    /// ground_speed from `adsb::AirborneVelocity::calculate()`
+    /// magnetic heading from `adsb::AirborneVelocity::velocity()` with airspeed
+    pub magnetic_heading: Option<f32>,
+    /// ground speed from `adsb::AirborneVelocity::velocity()`
     ///
     /// Stored as a f64 in that library but we store as f32 for size reasons in this library
     pub speed: Option<f32>,
-    /// vert_speed from `adsb::AirborneVelocity::calculate()`
+    /// vertical rate from `adsb::AirborneVelocity::velocity()`
     pub vert_speed: Option<i16>,
     pub on_ground: Option<bool>,
     pub num_messages: u32,
@@ -375,7 +435,9 @@ impl Default for AirplaneState {
             coords: AirplaneCoor::default(),
             squawk: None,
             callsign: None,
+            category: None,
             heading: None,
+            magnetic_heading: None,
             speed: None,
             vert_speed: None,
             on_ground: None,
@@ -387,6 +449,14 @@ impl Default for AirplaneState {
     }
 }
 
+/// Ground speed and track from ADS-B airborne velocity, to narrow down the registers of Comm-B
+/// replies with [`bds::infer_with_context`]
+impl From<&AirplaneState> for bds::Context {
+    fn from(state: &AirplaneState) -> Self {
+        Self { ground_speed: state.speed, heading: state.heading }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AirplaneCoor {